@@ -0,0 +1,37 @@
+//! Compare copying limbs with round-tripping through little-endian bytes.
+//!
+//! There are no dependencies, so this is only a rough comparison:
+//! ```text
+//! cargo bench --bench uint
+//! ```
+//! The byte-based path is what `--cfg alloy_compat_uint_bytes` builds.
+
+use alloy_compat::Compat as _;
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 10_000_000;
+
+fn bench(name: &str, f: impl Fn(alloy::U256) -> eth::U256) -> Duration {
+    let input = alloy::U256::from_limbs([1, 2, 3, 4]);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f(black_box(input)));
+    }
+    let elapsed = start.elapsed();
+    println!("{name}: {:?} per conversion", elapsed / ITERATIONS);
+    elapsed
+}
+
+fn main() {
+    let limbs = bench("limbs", |alloy| alloy.compat());
+    let bytes = bench("bytes", |alloy| {
+        eth::U256::from_little_endian(&alloy.to_le_bytes::<32>())
+    });
+    println!(
+        "bytes / limbs: {:.2}",
+        bytes.as_secs_f64() / limbs.as_secs_f64()
+    );
+}
@@ -0,0 +1,96 @@
+//! `#[derive(Compat)]` for [`alloy-compat`](https://docs.rs/alloy-compat).
+//!
+//! See the re-export in that crate for documentation.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens as _};
+use syn::{parse_macro_input, Data, DeriveInput, LitStr, Path, PathArguments};
+
+#[proc_macro_derive(Compat, attributes(compat))]
+pub fn derive_compat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`#[derive(Compat)]` only supports structs",
+        ));
+    };
+
+    let mut targets = vec![];
+    for attr in &input.attrs {
+        if attr.path().is_ident("compat") {
+            attr.parse_nested_meta(|meta| match meta.path.is_ident("into") {
+                true => {
+                    targets.push(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                    Ok(())
+                }
+                false => Err(meta.error("expected `into = \"path::To::Other\"`")),
+            })?;
+        }
+    }
+    if targets.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "expected at least one `#[compat(into = \"path::To::Other\")]`",
+        ));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(ix, field)| match &field.ident {
+            Some(it) => (quote!(#it), it.clone()),
+            None => (
+                syn::Index::from(ix).into_token_stream(),
+                format_ident!("_{ix}"),
+            ),
+        })
+        .collect::<Vec<_>>();
+    let members = fields.iter().map(|(member, _)| member);
+    let bindings = fields.iter().map(|(_, binding)| binding);
+    // `Foo { 0: _0 }` is valid for tuple structs, and `Foo {}` for unit structs
+    let destructure = quote!(#ident { #(#members: #bindings),* });
+
+    Ok(targets
+        .into_iter()
+        .map(|target| {
+            let ctor = turbofish(&target);
+            let members = fields.iter().map(|(member, _)| member);
+            let conversions = fields
+                .iter()
+                .map(|(_, binding)| quote!(::alloy_compat::__private::Compat::compat(#binding)));
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::alloy_compat::__private::Compat<#target>
+                    for #ident #ty_generics #where_clause
+                {
+                    #[inline]
+                    fn compat(self) -> #target {
+                        let #destructure = self;
+                        #ctor { #(#members: #conversions),* }
+                    }
+                }
+            }
+        })
+        .collect())
+}
+
+/// `path::To<T>` to `path::To::<T>`, for use in expression position.
+fn turbofish(path: &Path) -> Path {
+    let mut path = path.clone();
+    for segment in &mut path.segments {
+        if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.colon2_token = Some(Default::default());
+        }
+    }
+    path
+}
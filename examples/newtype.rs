@@ -0,0 +1,33 @@
+//! Implementing [`alloy_compat::unstable::Compat`] for a local newtype.
+
+use alloy_compat::Compat as _;
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StateRoot(alloy::B256);
+
+impl alloy_compat::unstable::Compat<eth::H256> for StateRoot {
+    fn compat(self) -> eth::H256 {
+        let Self(alloy::FixedBytes(bytes)) = self;
+        eth::H256(bytes)
+    }
+}
+
+impl alloy_compat::unstable::Compat<StateRoot> for eth::H256 {
+    fn compat(self) -> StateRoot {
+        let eth::H256(bytes) = self;
+        StateRoot(alloy::FixedBytes(bytes))
+    }
+}
+
+fn main() {
+    let root = StateRoot(alloy::B256::repeat_byte(0xde));
+    let eth: eth::H256 = root.compat();
+    assert_eq!(eth, eth::H256::repeat_byte(0xde));
+    assert_eq!(root, eth.compat());
+
+    // containers come for free
+    let roots: Vec<eth::H256> = vec![root; 2].compat();
+    assert_eq!(roots, [eth; 2]);
+}
@@ -0,0 +1,443 @@
+//! Conversions as free functions.
+//!
+//! These are `const`, since trait methods can't yet be,
+//! and the [`Compat`](crate::Compat) impls for the same pairs call them.
+//! They cover every infallible conversion between single values:
+//! fixed bytes, addresses, blooms, signatures,
+//! integers (including signed integers, which are reinterpreted),
+//! integers to and from big-endian fixed bytes,
+//! and each of these to and from raw arrays and primitive integers.
+//!
+//! Containers, [`alloy_primitives::Bytes`], and fallible conversions
+//! are only available through the traits.
+//!
+//! Every function is named `{source}_to_{target}`,
+//! where a type is prefixed by its crate, e.g. `alloy_b256` or `eth_h256`.
+//! `FixedBytes<N>` without an alias is `fixed_bytes_N`.
+//! Functions which reinterpret a reference use `_as_` instead of `_to_`.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! use alloy_compat::convert;
+//!
+//! static EMPTY_ROOT: eth::H256 = convert::alloy_b256_to_eth_h256(alloy::b256!(
+//!     "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+//! ));
+//! ```
+
+use alloy_primitives::FixedBytes;
+
+macro_rules! fixed_bytes {
+    ($($to_eth:ident, $to_alloy:ident : $alloy:ty, $eth:ident);* $(;)?) => {
+        $(
+            #[doc = concat!(
+                "Convert an [`", stringify!($alloy),
+                "`] to an [`ethereum_types::", stringify!($eth), "`]."
+            )]
+            #[inline]
+            pub const fn $to_eth(alloy: $alloy) -> ethereum_types::$eth {
+                let FixedBytes(bytes) = alloy;
+                ethereum_types::$eth(bytes)
+            }
+            #[doc = concat!(
+                "Convert an [`ethereum_types::", stringify!($eth),
+                "`] to an [`", stringify!($alloy), "`]."
+            )]
+            #[inline]
+            pub const fn $to_alloy(eth: ethereum_types::$eth) -> $alloy {
+                let ethereum_types::$eth(bytes) = eth;
+                FixedBytes(bytes)
+            }
+        )*
+    };
+}
+
+fixed_bytes! {
+    alloy_b32_to_eth_h32, eth_h32_to_alloy_b32 : alloy_primitives::aliases::B32, H32;
+    alloy_b64_to_eth_h64, eth_h64_to_alloy_b64 : alloy_primitives::B64, H64;
+    alloy_b128_to_eth_h128, eth_h128_to_alloy_b128 : alloy_primitives::B128, H128;
+    alloy_fixed_bytes_20_to_eth_h160, eth_h160_to_alloy_fixed_bytes_20 : alloy_primitives::FixedBytes<20>, H160;
+    alloy_b256_to_eth_h256, eth_h256_to_alloy_b256 : alloy_primitives::B256, H256;
+    alloy_fixed_bytes_33_to_eth_h264, eth_h264_to_alloy_fixed_bytes_33 : alloy_primitives::FixedBytes<33>, H264;
+    alloy_b512_to_eth_h512, eth_h512_to_alloy_b512 : alloy_primitives::B512, H512;
+    alloy_fixed_bytes_65_to_eth_h520, eth_h520_to_alloy_fixed_bytes_65 : alloy_primitives::FixedBytes<65>, H520;
+}
+
+/// Convert an [`alloy_primitives::Address`] to an [`ethereum_types::Address`].
+#[inline]
+pub const fn alloy_address_to_eth_address(
+    alloy: alloy_primitives::Address,
+) -> ethereum_types::Address {
+    let alloy_primitives::Address(FixedBytes(bytes)) = alloy;
+    ethereum_types::H160(bytes)
+}
+
+/// Convert an [`ethereum_types::Address`] to an [`alloy_primitives::Address`].
+#[inline]
+pub const fn eth_address_to_alloy_address(
+    eth: ethereum_types::Address,
+) -> alloy_primitives::Address {
+    let ethereum_types::H160(bytes) = eth;
+    alloy_primitives::Address(FixedBytes(bytes))
+}
+
+/// Convert an [`alloy_primitives::Bloom`] to an [`ethereum_types::Bloom`].
+#[inline]
+pub const fn alloy_bloom_to_eth_bloom(alloy: alloy_primitives::Bloom) -> ethereum_types::Bloom {
+    let alloy_primitives::Bloom(FixedBytes(bytes)) = alloy;
+    ethereum_types::Bloom(bytes)
+}
+
+/// Convert an [`ethereum_types::Bloom`] to an [`alloy_primitives::Bloom`].
+#[inline]
+pub const fn eth_bloom_to_alloy_bloom(eth: ethereum_types::Bloom) -> alloy_primitives::Bloom {
+    let ethereum_types::Bloom(bytes) = eth;
+    alloy_primitives::Bloom(FixedBytes(bytes))
+}
+
+/// Reinterpret a reference to an [`alloy_primitives::Bloom`] as a reference to an [`ethereum_types::Bloom`],
+/// without copying.
+///
+/// See also [`Compat::compat_view`](crate::Compat::compat_view).
+#[inline]
+pub const fn alloy_bloom_as_eth_bloom(alloy: &alloy_primitives::Bloom) -> &ethereum_types::Bloom {
+    // SAFETY: both are single-field wrappers around `[u8; 256]` with the same size and alignment,
+    //         which is asserted where `sealed::SameLayout` is implemented.
+    unsafe { &*(alloy as *const alloy_primitives::Bloom).cast::<ethereum_types::Bloom>() }
+}
+
+/// Reinterpret a reference to an [`ethereum_types::Bloom`] as a reference to an [`alloy_primitives::Bloom`],
+/// without copying.
+///
+/// See also [`Compat::compat_view`](crate::Compat::compat_view).
+#[inline]
+pub const fn eth_bloom_as_alloy_bloom(eth: &ethereum_types::Bloom) -> &alloy_primitives::Bloom {
+    // SAFETY: as above.
+    unsafe { &*(eth as *const ethereum_types::Bloom).cast::<alloy_primitives::Bloom>() }
+}
+
+/// Convert an [`alloy_primitives::FixedBytes`] to a raw array.
+#[inline]
+pub const fn alloy_fixed_bytes_to_array<const N: usize>(alloy: FixedBytes<N>) -> [u8; N] {
+    let FixedBytes(bytes) = alloy;
+    bytes
+}
+
+/// Convert a raw array to an [`alloy_primitives::FixedBytes`].
+#[inline]
+pub const fn array_to_alloy_fixed_bytes<const N: usize>(bytes: [u8; N]) -> FixedBytes<N> {
+    FixedBytes(bytes)
+}
+
+/// Convert a raw array to an [`alloy_primitives::Address`].
+#[inline]
+pub const fn array_to_alloy_address(bytes: [u8; 20]) -> alloy_primitives::Address {
+    alloy_primitives::Address(FixedBytes(bytes))
+}
+
+/// Convert an [`alloy_primitives::Bloom`] to a raw array.
+#[inline]
+pub const fn alloy_bloom_to_array(alloy: alloy_primitives::Bloom) -> [u8; 256] {
+    let alloy_primitives::Bloom(FixedBytes(bytes)) = alloy;
+    bytes
+}
+
+/// Convert a raw array to an [`alloy_primitives::Bloom`].
+#[inline]
+pub const fn array_to_alloy_bloom(bytes: [u8; 256]) -> alloy_primitives::Bloom {
+    alloy_primitives::Bloom(FixedBytes(bytes))
+}
+
+/// Convert an [`ethereum_types::Bloom`] to a raw array.
+#[inline]
+pub const fn eth_bloom_to_array(eth: ethereum_types::Bloom) -> [u8; 256] {
+    let ethereum_types::Bloom(bytes) = eth;
+    bytes
+}
+
+/// Convert a raw array to an [`ethereum_types::Bloom`].
+#[inline]
+pub const fn array_to_eth_bloom(bytes: [u8; 256]) -> ethereum_types::Bloom {
+    ethereum_types::Bloom(bytes)
+}
+
+macro_rules! array_to_eth {
+    ($($to_eth:ident : $n:literal, $eth:ident);* $(;)?) => {
+        $(
+            #[doc = concat!("Convert a raw array to an [`ethereum_types::", stringify!($eth), "`].")]
+            #[inline]
+            pub const fn $to_eth(bytes: [u8; $n]) -> ethereum_types::$eth {
+                ethereum_types::$eth(bytes)
+            }
+        )*
+    };
+}
+
+array_to_eth! {
+    array_to_eth_h32 : 4, H32;
+    array_to_eth_h64 : 8, H64;
+    array_to_eth_h128 : 16, H128;
+    array_to_eth_h160 : 20, H160;
+    array_to_eth_h256 : 32, H256;
+    array_to_eth_h264 : 33, H264;
+    array_to_eth_h512 : 64, H512;
+    array_to_eth_h520 : 65, H520;
+}
+
+macro_rules! uint_limbs {
+    ($(
+        $alloy_limbs:ident, $alloy_from_limbs:ident, $eth_limbs:ident, $eth_from_limbs:ident
+        : $alloy:ident, $eth:ident, $n:literal
+    );* $(;)?) => {
+        $(
+            #[doc = concat!("The little-endian limbs of an [`alloy_primitives::", stringify!($alloy), "`].")]
+            #[inline]
+            pub const fn $alloy_limbs(alloy: alloy_primitives::$alloy) -> [u64; $n] {
+                alloy.into_limbs()
+            }
+            #[doc = concat!("Build an [`alloy_primitives::", stringify!($alloy), "`] from little-endian limbs.")]
+            #[inline]
+            pub const fn $alloy_from_limbs(limbs: [u64; $n]) -> alloy_primitives::$alloy {
+                alloy_primitives::$alloy::from_limbs(limbs)
+            }
+            #[doc = concat!("The little-endian limbs of an [`ethereum_types::", stringify!($eth), "`].")]
+            #[inline]
+            pub const fn $eth_limbs(eth: ethereum_types::$eth) -> [u64; $n] {
+                let ethereum_types::$eth(limbs) = eth;
+                limbs
+            }
+            #[doc = concat!("Build an [`ethereum_types::", stringify!($eth), "`] from little-endian limbs.")]
+            #[inline]
+            pub const fn $eth_from_limbs(limbs: [u64; $n]) -> ethereum_types::$eth {
+                ethereum_types::$eth(limbs)
+            }
+        )*
+    };
+}
+
+// Both crates store integers as little-endian `u64` limbs,
+// so the least significant limb is first.
+uint_limbs! {
+    alloy_u64_limbs, alloy_u64_from_limbs, eth_u64_limbs, eth_u64_from_limbs : U64, U64, 1;
+    alloy_u128_limbs, alloy_u128_from_limbs, eth_u128_limbs, eth_u128_from_limbs : U128, U128, 2;
+    alloy_u256_limbs, alloy_u256_from_limbs, eth_u256_limbs, eth_u256_from_limbs : U256, U256, 4;
+    alloy_u512_limbs, alloy_u512_from_limbs, eth_u512_limbs, eth_u512_from_limbs : U512, U512, 8;
+}
+
+macro_rules! uint {
+    ($($to_eth:ident, $to_alloy:ident : $alloy:ident, $eth:ident);* $(;)?) => {
+        $(
+            #[doc = concat!(
+                "Convert an [`alloy_primitives::", stringify!($alloy),
+                "`] to an [`ethereum_types::", stringify!($eth), "`]."
+            )]
+            #[inline]
+            pub const fn $to_eth(alloy: alloy_primitives::$alloy) -> ethereum_types::$eth {
+                ethereum_types::$eth(alloy.into_limbs())
+            }
+            #[doc = concat!(
+                "Convert an [`ethereum_types::", stringify!($eth),
+                "`] to an [`alloy_primitives::", stringify!($alloy), "`]."
+            )]
+            #[inline]
+            pub const fn $to_alloy(eth: ethereum_types::$eth) -> alloy_primitives::$alloy {
+                let ethereum_types::$eth(limbs) = eth;
+                alloy_primitives::$alloy::from_limbs(limbs)
+            }
+        )*
+    };
+}
+
+uint! {
+    alloy_u64_to_eth_u64, eth_u64_to_alloy_u64 : U64, U64;
+    alloy_u128_to_eth_u128, eth_u128_to_alloy_u128 : U128, U128;
+    alloy_u256_to_eth_u256, eth_u256_to_alloy_u256 : U256, U256;
+    alloy_u512_to_eth_u512, eth_u512_to_alloy_u512 : U512, U512;
+}
+
+/// Convert an [`alloy_primitives::aliases::U160`] to an [`ethereum_types::U256`],
+/// zero-extending, since [`ethereum_types`] has no 160-bit integer.
+#[inline]
+pub const fn alloy_u160_to_eth_u256(
+    alloy: alloy_primitives::aliases::U160,
+) -> ethereum_types::U256 {
+    let [a, b, c] = alloy.into_limbs();
+    ethereum_types::U256([a, b, c, 0])
+}
+
+macro_rules! signed {
+    ($($to_eth:ident, $to_alloy:ident : $alloy:ident, $eth:ident);* $(;)?) => {
+        $(
+            #[doc = concat!(
+                "Reinterpret an [`alloy_primitives::aliases::", stringify!($alloy),
+                "`] as an [`ethereum_types::", stringify!($eth), "`], in two's complement."
+            )]
+            #[inline]
+            pub const fn $to_eth(alloy: alloy_primitives::aliases::$alloy) -> ethereum_types::$eth {
+                ethereum_types::$eth(alloy.into_raw().into_limbs())
+            }
+            #[doc = concat!(
+                "Reinterpret an [`ethereum_types::", stringify!($eth),
+                "`] as an [`alloy_primitives::aliases::", stringify!($alloy), "`], in two's complement."
+            )]
+            #[inline]
+            pub const fn $to_alloy(eth: ethereum_types::$eth) -> alloy_primitives::aliases::$alloy {
+                let ethereum_types::$eth(limbs) = eth;
+                alloy_primitives::aliases::$alloy::from_raw(alloy_primitives::Uint::from_limbs(limbs))
+            }
+        )*
+    };
+}
+
+signed! {
+    alloy_i64_to_eth_u64, eth_u64_to_alloy_i64 : I64, U64;
+    alloy_i128_to_eth_u128, eth_u128_to_alloy_i128 : I128, U128;
+    alloy_i256_to_eth_u256, eth_u256_to_alloy_i256 : I256, U256;
+    alloy_i512_to_eth_u512, eth_u512_to_alloy_i512 : I512, U512;
+}
+
+/// Convert an [`alloy_primitives::U64`] to a [`u64`].
+#[inline]
+pub const fn alloy_u64_to_u64(alloy: alloy_primitives::U64) -> u64 {
+    let [limb] = alloy.into_limbs();
+    limb
+}
+
+/// Convert a [`u64`] to an [`alloy_primitives::U64`].
+#[inline]
+pub const fn u64_to_alloy_u64(value: u64) -> alloy_primitives::U64 {
+    alloy_primitives::U64::from_limbs([value])
+}
+
+/// Convert an [`ethereum_types::U64`] to a [`u64`].
+#[inline]
+pub const fn eth_u64_to_u64(eth: ethereum_types::U64) -> u64 {
+    let ethereum_types::U64([limb]) = eth;
+    limb
+}
+
+/// Convert a [`u64`] to an [`ethereum_types::U64`].
+#[inline]
+pub const fn u64_to_eth_u64(value: u64) -> ethereum_types::U64 {
+    ethereum_types::U64([value])
+}
+
+/// Convert an [`alloy_primitives::U128`] to a [`u128`].
+#[inline]
+pub const fn alloy_u128_to_u128(alloy: alloy_primitives::U128) -> u128 {
+    let [low, high] = alloy.into_limbs();
+    (high as u128) << 64 | low as u128
+}
+
+/// Convert a [`u128`] to an [`alloy_primitives::U128`].
+#[inline]
+pub const fn u128_to_alloy_u128(value: u128) -> alloy_primitives::U128 {
+    alloy_primitives::U128::from_limbs([value as u64, (value >> 64) as u64])
+}
+
+/// Convert an [`ethereum_types::U128`] to a [`u128`].
+#[inline]
+pub const fn eth_u128_to_u128(eth: ethereum_types::U128) -> u128 {
+    let ethereum_types::U128([low, high]) = eth;
+    (high as u128) << 64 | low as u128
+}
+
+/// Convert a [`u128`] to an [`ethereum_types::U128`].
+#[inline]
+pub const fn u128_to_eth_u128(value: u128) -> ethereum_types::U128 {
+    ethereum_types::U128([value as u64, (value >> 64) as u64])
+}
+
+/// Little-endian limbs to big-endian bytes.
+const fn be_bytes<const L: usize, const N: usize>(limbs: [u64; L]) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut i = 0;
+    while i < N {
+        bytes[N - 1 - i] = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+        i += 1;
+    }
+    bytes
+}
+
+/// Big-endian bytes to little-endian limbs.
+const fn be_limbs<const N: usize, const L: usize>(bytes: [u8; N]) -> [u64; L] {
+    let mut limbs = [0; L];
+    let mut i = 0;
+    while i < N {
+        limbs[i / 8] |= (bytes[N - 1 - i] as u64) << (8 * (i % 8));
+        i += 1;
+    }
+    limbs
+}
+
+macro_rules! uint_fixed_bytes {
+    ($(
+        $alloy_to_eth:ident, $eth_to_alloy:ident, $eth_to_alloy_bytes:ident, $alloy_to_eth_uint:ident
+        : $alloy_uint:ident, $alloy_bytes:ident, $eth_uint:ident, $eth_bytes:ident
+    );* $(;)?) => {
+        $(
+            #[doc = concat!(
+                "Convert an [`alloy_primitives::", stringify!($alloy_uint),
+                "`] to a big-endian [`ethereum_types::", stringify!($eth_bytes), "`]."
+            )]
+            #[inline]
+            pub const fn $alloy_to_eth(alloy: alloy_primitives::$alloy_uint) -> ethereum_types::$eth_bytes {
+                ethereum_types::$eth_bytes(be_bytes(alloy.into_limbs()))
+            }
+            #[doc = concat!(
+                "Convert a big-endian [`ethereum_types::", stringify!($eth_bytes),
+                "`] to an [`alloy_primitives::", stringify!($alloy_uint), "`]."
+            )]
+            #[inline]
+            pub const fn $eth_to_alloy(eth: ethereum_types::$eth_bytes) -> alloy_primitives::$alloy_uint {
+                let ethereum_types::$eth_bytes(bytes) = eth;
+                alloy_primitives::$alloy_uint::from_limbs(be_limbs(bytes))
+            }
+            #[doc = concat!(
+                "Convert an [`ethereum_types::", stringify!($eth_uint),
+                "`] to a big-endian [`alloy_primitives::", stringify!($alloy_bytes), "`]."
+            )]
+            #[inline]
+            pub const fn $eth_to_alloy_bytes(eth: ethereum_types::$eth_uint) -> alloy_primitives::$alloy_bytes {
+                let ethereum_types::$eth_uint(limbs) = eth;
+                FixedBytes(be_bytes(limbs))
+            }
+            #[doc = concat!(
+                "Convert a big-endian [`alloy_primitives::", stringify!($alloy_bytes),
+                "`] to an [`ethereum_types::", stringify!($eth_uint), "`]."
+            )]
+            #[inline]
+            pub const fn $alloy_to_eth_uint(alloy: alloy_primitives::$alloy_bytes) -> ethereum_types::$eth_uint {
+                let FixedBytes(bytes) = alloy;
+                ethereum_types::$eth_uint(be_limbs(bytes))
+            }
+        )*
+    };
+}
+
+// `Compat` calls these, so they are the only implementation of the big-endian conversions.
+uint_fixed_bytes! {
+    alloy_u64_to_eth_h64, eth_h64_to_alloy_u64, eth_u64_to_alloy_b64, alloy_b64_to_eth_u64 : U64, B64, U64, H64;
+    alloy_u128_to_eth_h128, eth_h128_to_alloy_u128, eth_u128_to_alloy_b128, alloy_b128_to_eth_u128 : U128, B128, U128, H128;
+    alloy_u256_to_eth_h256, eth_h256_to_alloy_u256, eth_u256_to_alloy_b256, alloy_b256_to_eth_u256 : U256, B256, U256, H256;
+    alloy_u512_to_eth_h512, eth_h512_to_alloy_u512, eth_u512_to_alloy_b512, alloy_b512_to_eth_u512 : U512, B512, U512, H512;
+}
+
+/// Convert an [`alloy_primitives::PrimitiveSignature`] to `r || s || v`, big-endian,
+/// where `v` is `27 + recovery_id`.
+#[inline]
+pub const fn alloy_primitive_signature_to_eth_h520(
+    alloy: alloy_primitives::PrimitiveSignature,
+) -> ethereum_types::H520 {
+    let r: [u8; 32] = be_bytes(alloy.r().into_limbs());
+    let s: [u8; 32] = be_bytes(alloy.s().into_limbs());
+    let mut bytes = [0; 65];
+    let mut i = 0;
+    while i < 32 {
+        bytes[i] = r[i];
+        bytes[32 + i] = s[i];
+        i += 1;
+    }
+    bytes[64] = 27 + alloy.v() as u8;
+    ethereum_types::H520(bytes)
+}
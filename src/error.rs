@@ -0,0 +1,51 @@
+//! The error type for fallible conversions.
+
+use core::fmt;
+
+/// The error returned by [`TryCompat`](crate::TryCompat) and other fallible conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompatError {
+    /// The value does not fit in the target type.
+    Overflow,
+    /// The signature's `v` value is not a valid parity.
+    InvalidParity,
+    /// The input has the wrong number of bytes.
+    WrongLength { expected: usize, got: usize },
+    /// The bytes which would be discarded are not all zero.
+    NonZeroPadding,
+    /// The input is not valid hex, or has the wrong length.
+    Parse(alloy_primitives::hex::FromHexError),
+    /// A log has more than four topics.
+    TooManyTopics,
+}
+
+impl fmt::Display for CompatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompatError::Overflow => f.write_str("value does not fit in the target type"),
+            CompatError::InvalidParity => f.write_str("invalid signature parity"),
+            CompatError::WrongLength { expected, got } => {
+                write!(f, "expected {expected} bytes, got {got}")
+            }
+            CompatError::NonZeroPadding => f.write_str("padding bytes are not zero"),
+            CompatError::Parse(e) => write!(f, "invalid hex: {e}"),
+            CompatError::TooManyTopics => f.write_str("a log may have at most 4 topics"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CompatError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// `core::error::Error` is `std::error::Error` where both exist, so only one of these may apply.
+// `FromHexError` only implements the `std` trait, so there is no source here.
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for CompatError {}
@@ -16,140 +16,4108 @@
 //! let int = eth::U128::MAX;
 //! assert_eq!(alloy::U128::MAX, int.compat());
 //! ```
+//!
+//! Many types convert to several targets, so the target must usually be named,
+//! with a type annotation or a turbofish.
+//! For example, [`ethereum_types::H160`] converts to both [`alloy_primitives::Address`]
+//! and [`alloy_primitives::FixedBytes<20>`],
+//! and [`alloy_primitives::B256`] converts to [`ethereum_types::H256`], [`ethereum_types::U256`] and `[u8; 32]`.
+//! Since 0.3, code which relied on inference for these may need annotating.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! # use alloy_compat::Compat as _;
+//! let address = eth::H160::zero().compat::<alloy::Address>();
+//! let bytes: [u8; 32] = alloy::B256::ZERO.compat();
+//! ```
+//!
+//! Integers are converted by copying their little-endian `u64` limbs.
+//! Both crates store integers this way on every target.
+//! Building with `--cfg alloy_compat_uint_bytes` round-trips through little-endian bytes instead,
+//! which is slower (see `benches/uint.rs`), but doesn't rely on the representation.
+//!
+//! Integers and fixed bytes of the same width may be converted into each other,
+//! using big-endian byte order, as is used for storage slots.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! # use alloy_compat::Compat as _;
+//! let slot = alloy::U256::from(1);
+//! let hash: eth::H256 = slot.compat();
+//! assert_eq!(hash[31], 1);
+//! ```
+//!
+//! `U64` and `U128` may also be converted to and from [`u64`] and [`u128`].
+//! These are infallible, since the widths are the same.
+//! Since a primitive converts to both crates' types,
+//! the target must always be named.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! # use alloy_compat::Compat as _;
+//! let number: u64 = alloy::U64::from(1).compat();
+//! assert_eq!(number.compat::<eth::U64>(), eth::U64::one());
+//!
+//! // e.g. block numbers
+//! let number: alloy::BlockNumber = eth::U64::from(21_000_000).compat();
+//! ```
+//!
+//! [`alloy_primitives::PrimitiveSignature`] may be decomposed into `(r, s, recovery_id)`,
+//! and fallibly reassembled.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! # use alloy_compat::{Compat as _, TryCompat as _};
+//! let signature = alloy::PrimitiveSignature::new(alloy::U256::from(1), alloy::U256::from(2), true);
+//! let (r, s, v): (eth::H256, eth::H256, u8) = signature.compat();
+//! assert_eq!(v, 1);
+//! assert_eq!((r, s, 28).try_compat(), Ok(signature));
+//! ```
+//!
+//! It may also be converted to the 65-byte `r || s || v` form as an [`ethereum_types::H520`],
+//! where `v` is `27` or `28`.
+//!
+//! [`ethereum_types`] re-exports `H128`, `H160`, `H256`, `H512`, `U128`, `U256` and `U512`
+//! from `primitive_types`, so those conversions work for `primitive_types` too.
+//! Similarly, [`ethereum_types::Secret`] and [`ethereum_types::Public`] are aliases for
+//! [`H256`](ethereum_types::H256) and [`H512`](ethereum_types::H512).
+//! [`alloy_primitives`]' domain names like [`TxHash`](alloy_primitives::TxHash),
+//! [`BlockHash`](alloy_primitives::BlockHash) and [`StorageKey`](alloy_primitives::StorageKey)
+//! are aliases for [`B256`](alloy_primitives::B256), so may be used as targets directly.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! # use alloy_compat::Compat as _;
+//! let tx_hash: alloy::TxHash = eth::H256::zero().compat();
+//! ```
 #![cfg_attr(not(feature = "std"), no_std)]
 
-/// Convert between an [`ethereum_types`] type and an [`alloy_primitives`] type.
-///
-/// See [module documentation](mod@self) more.
-pub trait Compat {
-    fn compat<T>(self) -> T
-    where
-        Self: sealed::Compat<T>;
-}
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rlp")]
+pub mod rlp;
+#[cfg(feature = "scale")]
+pub mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
+
+pub mod convert;
+mod error;
+
+pub use error::CompatError;
+
+use alloy_primitives::Uint;
+use core::{iter::FusedIterator, marker::PhantomData};
+
+/// Convert between an [`ethereum_types`] type and an [`alloy_primitives`] type.
+///
+/// See [module documentation](mod@self) more.
+///
+/// This exists because [`From`] can't be implemented between these types:
+/// neither the trait nor the types are local to this crate.
+pub trait Compat {
+    fn compat<T>(self) -> T
+    where
+        Self: sealed::Compat<T>;
+    /// Convert from a shared reference, copying the underlying bytes.
+    fn compat_ref<T>(&self) -> T
+    where
+        Self: Copy + sealed::Compat<T>;
+    /// Convert from a shared reference, cloning first.
+    ///
+    /// Use this for types which aren't [`Copy`], like containers.
+    fn compat_clone<T>(&self) -> T
+    where
+        Self: Clone + sealed::Compat<T>;
+    /// Reinterpret a shared reference without copying.
+    ///
+    /// This is only implemented for types with the same layout,
+    /// such as [`alloy_primitives::Bloom`] and [`ethereum_types::Bloom`],
+    /// which is checked at compile time.
+    ///
+    /// ```
+    /// # use alloy_primitives as alloy; use ethereum_types as eth;
+    /// use alloy_compat::Compat as _;
+    ///
+    /// fn takes_eth(bloom: &eth::Bloom) {}
+    ///
+    /// let alloy = alloy::Bloom::ZERO;
+    /// takes_eth(alloy.compat_view());
+    /// ```
+    fn compat_view<T>(&self) -> &T
+    where
+        Self: sealed::SameLayout<T>;
+    /// Reinterpret an exclusive reference without copying,
+    /// so writes through the returned reference are visible in `self`.
+    ///
+    /// This is only implemented for types with the same layout,
+    /// such as [`alloy_primitives::B256`] and [`ethereum_types::H256`],
+    /// which is checked at compile time.
+    ///
+    /// ```
+    /// # use alloy_primitives as alloy; use ethereum_types as eth;
+    /// use alloy_compat::Compat as _;
+    ///
+    /// let mut alloy = alloy::B256::ZERO;
+    /// let eth: &mut eth::H256 = alloy.compat_mut();
+    /// eth.0[0] = 1;
+    /// assert_eq!(alloy[0], 1);
+    /// ```
+    fn compat_mut<T>(&mut self) -> &mut T
+    where
+        Self: sealed::SameLayout<T>;
+    /// Convert, and format the result as `0x`-prefixed lowercase hex.
+    ///
+    /// ```
+    /// # use alloy_primitives as alloy; use ethereum_types as eth;
+    /// use alloy_compat::Compat as _;
+    ///
+    /// let address = alloy::Address::repeat_byte(0xde);
+    /// assert_eq!(address.compat_hex::<eth::Address>(), format!("0x{}", "de".repeat(20)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn compat_hex<T>(self) -> alloc::string::String
+    where
+        Self: sealed::Compat<T>,
+        T: core::fmt::LowerHex;
+    /// Format an address from either crate with an [EIP-55] mixed-case checksum.
+    ///
+    /// ```
+    /// # use ethereum_types as eth;
+    /// use alloy_compat::Compat as _;
+    ///
+    /// let address: eth::Address = "d8da6bf26964af9d7eed9e03e53415d37aa96045".parse().unwrap();
+    /// assert_eq!(address.compat_checksum(), "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    /// ```
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    #[cfg(feature = "alloc")]
+    fn compat_checksum(self) -> alloc::string::String
+    where
+        Self: sealed::Address;
+}
+
+impl<T> Compat for T {
+    fn compat<U>(self) -> U
+    where
+        Self: sealed::Compat<U>,
+    {
+        sealed::Compat::compat(self)
+    }
+    fn compat_ref<U>(&self) -> U
+    where
+        Self: Copy + sealed::Compat<U>,
+    {
+        sealed::Compat::compat(*self)
+    }
+    fn compat_clone<U>(&self) -> U
+    where
+        Self: Clone + sealed::Compat<U>,
+    {
+        sealed::Compat::compat(self.clone())
+    }
+    fn compat_view<U>(&self) -> &U
+    where
+        Self: sealed::SameLayout<U>,
+    {
+        const {
+            assert!(core::mem::size_of::<T>() == core::mem::size_of::<U>());
+            assert!(core::mem::align_of::<T>() == core::mem::align_of::<U>());
+        }
+        // SAFETY:
+        // - `T` and `U` have the same size and alignment, so the pointer is aligned and valid.
+        // - `T` and `U` have the same valid bit patterns, so `self` is a valid `U`.
+        // - the returned reference borrows from `self`.
+        unsafe { &*(self as *const T).cast::<U>() }
+    }
+    fn compat_mut<U>(&mut self) -> &mut U
+    where
+        Self: sealed::SameLayout<U>,
+    {
+        const {
+            assert!(core::mem::size_of::<T>() == core::mem::size_of::<U>());
+            assert!(core::mem::align_of::<T>() == core::mem::align_of::<U>());
+        }
+        // SAFETY:
+        // - `T` and `U` have the same size and alignment, so the pointer is aligned and valid.
+        // - `T` and `U` have the same valid bit patterns,
+        //   so `self` is a valid `U`, and any `U` written through it is a valid `T`.
+        // - the returned reference borrows from `self`.
+        unsafe { &mut *(self as *mut T).cast::<U>() }
+    }
+    #[cfg(feature = "alloc")]
+    fn compat_hex<U>(self) -> alloc::string::String
+    where
+        Self: sealed::Compat<U>,
+        U: core::fmt::LowerHex,
+    {
+        alloc::format!("{:#x}", sealed::Compat::compat(self))
+    }
+    #[cfg(feature = "alloc")]
+    fn compat_checksum(self) -> alloc::string::String
+    where
+        Self: sealed::Address,
+    {
+        alloy_primitives::Address::from(self.to_bytes()).to_checksum(None)
+    }
+}
+
+/// Like [`From`], for [`Compat`] conversions.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatFrom as _;
+///
+/// let hash = eth::H256::compat_from(alloy::B256::ZERO);
+/// ```
+pub trait CompatFrom<T>: Sized {
+    fn compat_from(value: T) -> Self;
+}
+
+impl<T, U> CompatFrom<T> for U
+where
+    T: sealed::Compat<U>,
+{
+    fn compat_from(value: T) -> Self {
+        sealed::Compat::compat(value)
+    }
+}
+
+/// Construct an [`ethereum_types`] type from an [`alloy_primitives`] type.
+///
+/// This is [`CompatFrom`] with a name for the call site,
+/// so the source must be an [`alloy_primitives`] type, or a container of them.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::FromAlloy as _;
+///
+/// let hash = eth::H256::from_alloy(alloy::B256::ZERO);
+/// ```
+///
+/// Converting from an [`ethereum_types`] type fails to compile.
+///
+/// ```compile_fail
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::FromAlloy as _;
+///
+/// let hash = alloy::B256::from_alloy(eth::H256::zero());
+/// ```
+pub trait FromAlloy<T>: Sized {
+    fn from_alloy(value: T) -> Self;
+}
+
+impl<T, U> FromAlloy<T> for U
+where
+    T: sealed::Compat<U> + sealed::Alloy,
+{
+    fn from_alloy(value: T) -> Self {
+        sealed::Compat::compat(value)
+    }
+}
+
+/// Construct an [`alloy_primitives`] type from an [`ethereum_types`] type.
+///
+/// This is [`CompatFrom`] with a name for the call site,
+/// so the source must be an [`ethereum_types`] type, or a container of them.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::FromEth as _;
+///
+/// let hash = alloy::B256::from_eth(eth::H256::zero());
+/// ```
+///
+/// Converting from an [`alloy_primitives`] type fails to compile.
+///
+/// ```compile_fail
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::FromEth as _;
+///
+/// let hash = eth::H256::from_eth(alloy::B256::ZERO);
+/// ```
+pub trait FromEth<T>: Sized {
+    fn from_eth(value: T) -> Self;
+}
+
+impl<T, U> FromEth<T> for U
+where
+    T: sealed::Compat<U> + sealed::Eth,
+{
+    fn from_eth(value: T) -> Self {
+        sealed::Compat::compat(value)
+    }
+}
+
+/// Like [`Into`], for [`Compat`] conversions.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatInto as _;
+///
+/// let hash: eth::H256 = alloy::B256::ZERO.compat_into();
+/// ```
+pub trait CompatInto<T> {
+    fn compat_into(self) -> T;
+}
+
+impl<T, U> CompatInto<U> for T
+where
+    T: sealed::Compat<U>,
+{
+    fn compat_into(self) -> U {
+        sealed::Compat::compat(self)
+    }
+}
+
+/// Derive [`Compat`] for a struct, converting each field to the struct named by
+/// `#[compat(into = "...")]`, which must have the same fields.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::Compat;
+///
+/// #[derive(Compat)]
+/// #[compat(into = "EthAccount")]
+/// struct AlloyAccount {
+///     address: alloy::Address,
+///     balance: alloy::U256,
+/// }
+///
+/// struct EthAccount {
+///     address: eth::Address,
+///     balance: eth::U256,
+/// }
+///
+/// let account = AlloyAccount {
+///     address: alloy::Address::ZERO,
+///     balance: alloy::U256::from(1),
+/// };
+/// let account: EthAccount = account.compat();
+/// assert_eq!(account.balance, eth::U256::one());
+/// ```
+#[cfg(feature = "derive")]
+pub use alloy_compat_derive::Compat;
+
+/// Implement [`Compat`] for a generic tuple newtype, converting the wrapped value.
+///
+/// The value must be the first field.
+/// Any tag parameters must follow, with a [`PhantomData`] second field,
+/// which is re-tagged.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::Compat as _;
+/// use std::marker::PhantomData;
+///
+/// struct Checked<T>(T);
+/// alloy_compat::impl_compat_newtype!(Checked);
+///
+/// struct Wei;
+/// struct Tagged<T, Unit>(T, PhantomData<Unit>);
+/// alloy_compat::impl_compat_newtype!(Tagged<Unit>);
+///
+/// let Checked(hash): Checked<eth::H256> = Checked(alloy::B256::ZERO).compat();
+/// let Tagged(balance, _): Tagged<eth::U256, Wei> =
+///     Tagged(alloy::U256::from(1), PhantomData::<Wei>).compat();
+/// assert_eq!(balance, eth::U256::one());
+/// ```
+#[macro_export]
+macro_rules! impl_compat_newtype {
+    ($name:ident $(,)?) => {
+        impl<T, U> $crate::__private::Compat<$name<U>> for $name<T>
+        where
+            T: $crate::__private::Compat<U>,
+        {
+            #[inline]
+            fn compat(self) -> $name<U> {
+                let $name(inner) = self;
+                $name($crate::__private::Compat::compat(inner))
+            }
+        }
+        impl<T: $crate::__private::Alloy> $crate::__private::Alloy for $name<T> {}
+        impl<T: $crate::__private::Eth> $crate::__private::Eth for $name<T> {}
+    };
+    ($name:ident < $($tag:ident),+ $(,)? > $(,)?) => {
+        impl<T, U, $($tag),+> $crate::__private::Compat<$name<U, $($tag),+>> for $name<T, $($tag),+>
+        where
+            T: $crate::__private::Compat<U>,
+        {
+            #[inline]
+            fn compat(self) -> $name<U, $($tag),+> {
+                let $name(inner, _) = self;
+                $name($crate::__private::Compat::compat(inner), ::core::marker::PhantomData)
+            }
+        }
+        impl<T: $crate::__private::Alloy, $($tag),+> $crate::__private::Alloy for $name<T, $($tag),+> {}
+        impl<T: $crate::__private::Eth, $($tag),+> $crate::__private::Eth for $name<T, $($tag),+> {}
+    };
+}
+
+/// Assert at compile time that two types have the same size and alignment.
+///
+/// This is a necessary, but not sufficient, condition for transmuting between them:
+/// the types must also have the same valid bit patterns.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// alloy_compat::assert_layout_compatible!(alloy::B256, eth::H256);
+/// alloy_compat::assert_layout_compatible!(alloy::U256, eth::U256);
+/// ```
+///
+/// A mismatched pair fails to compile.
+///
+/// ```compile_fail
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// alloy_compat::assert_layout_compatible!(alloy::B256, eth::H160);
+/// ```
+#[macro_export]
+macro_rules! assert_layout_compatible {
+    ($left:ty, $right:ty $(,)?) => {
+        const _: () = {
+            assert!(
+                ::core::mem::size_of::<$left>() == ::core::mem::size_of::<$right>(),
+                concat!(
+                    "`",
+                    stringify!($left),
+                    "` and `",
+                    stringify!($right),
+                    "` differ in size"
+                ),
+            );
+            assert!(
+                ::core::mem::align_of::<$left>() == ::core::mem::align_of::<$right>(),
+                concat!(
+                    "`",
+                    stringify!($left),
+                    "` and `",
+                    stringify!($right),
+                    "` differ in alignment"
+                ),
+            );
+        };
+    };
+}
+
+/// The traits behind [`Compat`] and [`TryCompat`],
+/// for implementing conversions for your own types.
+///
+/// This is not covered by semver.
+///
+/// Implementing [`Compat`](unstable::Compat) for your type makes it usable with [`Compat`],
+/// and with containers like [`Option`] and [`Vec`] for free.
+/// Coherence rules mean you may only implement it where your type is `Self` or the target,
+/// and those containers are already covered,
+/// so e.g. `impl Compat<Option<eth::H256>> for Option<MyHash>` conflicts.
+/// To convert e.g. `Option<&MyHash>`, also implement `Compat<U> for &MyHash`,
+/// which the orphan rules allow.
+/// Your impls may also stop compiling if this crate later adds an overlapping impl,
+/// e.g. a blanket `impl<T> Compat<T> for alloy::B256` would conflict with your
+/// `impl Compat<MyHash> for alloy::B256`.
+///
+/// This feature only adds a documented name for these traits.
+/// The derive and [`impl_compat_newtype!`] reach them through a hidden module,
+/// which is not covered by semver either,
+/// so the trait isn't truly sealed without this feature - it's just unsupported.
+///
+/// See `examples/newtype.rs`.
+#[cfg(feature = "unstable-impl")]
+pub mod unstable {
+    pub use crate::sealed::{Compat, TryCompat};
+}
+
+// For macro expansions only, not covered by semver.
+// Implementing `Compat` through this is unsupported, see `unstable` instead.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "test-util")]
+    pub use crate::sealed::Counterpart;
+    pub use crate::sealed::{Alloy, Compat, Eth};
+
+    #[cfg(feature = "test-util")]
+    #[track_caller]
+    pub fn assert_compat_roundtrip<A, E>(alloy: A)
+    where
+        A: Compat<E> + Clone + PartialEq + core::fmt::Debug + ::serde::Serialize,
+        E: Compat<A> + Clone + ::serde::Serialize,
+    {
+        let eth = alloy.clone().compat();
+        assert_eq!(
+            serde_json::to_value(&alloy).unwrap(),
+            serde_json::to_value(&eth).unwrap(),
+            "different JSON representations"
+        );
+        assert_eq!(alloy, eth.compat());
+    }
+
+    #[cfg(feature = "test-util")]
+    #[track_caller]
+    pub fn assert_compat_roundtrip_counterpart<A>(alloy: A)
+    where
+        A: Counterpart + Clone + PartialEq + core::fmt::Debug + ::serde::Serialize,
+        A::Eth: Clone + ::serde::Serialize,
+    {
+        assert_compat_roundtrip::<A, A::Eth>(alloy)
+    }
+}
+
+/// Assert that an [`alloy_primitives`] value survives a round trip through
+/// [`ethereum_types`], and that both serialize to the same JSON.
+///
+/// The [`ethereum_types`] type may be omitted for types with an obvious counterpart.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::assert_compat_roundtrip;
+///
+/// assert_compat_roundtrip!(alloy::Address::repeat_byte(1));
+/// assert_compat_roundtrip!(alloy::U256::from(1), eth::U256);
+/// ```
+#[cfg(feature = "test-util")]
+#[macro_export]
+macro_rules! assert_compat_roundtrip {
+    ($alloy:expr $(,)?) => {
+        $crate::__private::assert_compat_roundtrip_counterpart($alloy)
+    };
+    ($alloy:expr, $eth:ty $(,)?) => {
+        $crate::__private::assert_compat_roundtrip::<_, $eth>($alloy)
+    };
+}
+
+/// Lazily [`Compat`] each item of an [`Iterator`].
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatIterator as _;
+///
+/// let hashes = [alloy::B256::ZERO, alloy::B256::repeat_byte(0xde)];
+/// let mut iter = hashes.into_iter().compat_iter::<eth::H256>();
+/// assert_eq!(iter.next(), Some(eth::H256::zero()));
+/// ```
+pub trait CompatIterator: Iterator {
+    fn compat_iter<T>(self) -> CompatIter<Self, T>
+    where
+        Self: Sized,
+        Self::Item: sealed::Compat<T>;
+}
+
+impl<I: Iterator> CompatIterator for I {
+    fn compat_iter<T>(self) -> CompatIter<Self, T>
+    where
+        Self: Sized,
+        Self::Item: sealed::Compat<T>,
+    {
+        CompatIter {
+            inner: self,
+            target: PhantomData,
+        }
+    }
+}
+
+/// An [`Iterator`] returned by [`CompatIterator::compat_iter`].
+#[derive(Debug, Clone)]
+pub struct CompatIter<I, T> {
+    inner: I,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<I, T> Iterator for CompatIter<I, T>
+where
+    I: Iterator,
+    I::Item: sealed::Compat<T>,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().map(sealed::Compat::compat)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for CompatIter<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: sealed::Compat<T>,
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back().map(sealed::Compat::compat)
+    }
+}
+
+impl<I, T> ExactSizeIterator for CompatIter<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: sealed::Compat<T>,
+{
+}
+
+impl<I, T> FusedIterator for CompatIter<I, T>
+where
+    I: FusedIterator,
+    I::Item: sealed::Compat<T>,
+{
+}
+
+/// [`Compat`] each item of an [`Iterator`], and [`collect`](Iterator::collect) them.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatCollect as _;
+///
+/// let hashes = [alloy::B256::ZERO, alloy::B256::repeat_byte(0xde)];
+/// let hashes = hashes.into_iter().compat_collect::<Vec<eth::H256>>();
+/// assert_eq!(hashes[0], eth::H256::zero());
+/// ```
+pub trait CompatCollect: Iterator {
+    fn compat_collect<B>(self) -> B
+    where
+        Self: Sized,
+        B: IntoIterator + FromIterator<B::Item>,
+        Self::Item: sealed::Compat<B::Item>;
+}
+
+impl<I: Iterator> CompatCollect for I {
+    fn compat_collect<B>(self) -> B
+    where
+        Self: Sized,
+        B: IntoIterator + FromIterator<B::Item>,
+        Self::Item: sealed::Compat<B::Item>,
+    {
+        self.map(sealed::Compat::compat).collect()
+    }
+}
+
+/// Convert a larger [`ethereum_types`] type to a smaller [`alloy_primitives`] type,
+/// or vice-versa, by discarding the high bytes.
+///
+/// An `N`-byte value is truncated to `M` bytes by keeping the rightmost (low-order) bytes,
+/// `N - M..N`.
+/// So a 32-byte hash is truncated to an address by keeping bytes `12..32`,
+/// as when deriving an address from a `keccak256` output.
+///
+/// Implemented from 512, 256 and 128 bits to each smaller width,
+/// and from 256 bits to an address.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatTruncate as _;
+///
+/// let hash = alloy::b256!("000000000000000000000000deadbeefdeadbeefdeadbeefdeadbeef00000000");
+/// let address: eth::Address = hash.compat_truncate();
+/// assert_eq!(address, eth::Address::from_slice(&hash[12..]));
+/// ```
+pub trait CompatTruncate {
+    fn compat_truncate<T>(self) -> T
+    where
+        Self: sealed::CompatTruncate<T>;
+    /// Keep the rightmost `N` bytes of an [`ethereum_types::H256`],
+    /// for any `N` up to `32`, which is checked at compile time.
+    ///
+    /// ```
+    /// # use ethereum_types as eth;
+    /// use alloy_compat::CompatTruncate as _;
+    ///
+    /// let selector = eth::H256::from_low_u64_be(0xdeadbeef).compat_truncate_to::<4>();
+    /// assert_eq!(selector, [0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use ethereum_types as eth;
+    /// # use alloy_compat::CompatTruncate as _;
+    /// eth::H256::zero().compat_truncate_to::<33>();
+    /// ```
+    fn compat_truncate_to<const N: usize>(self) -> alloy_primitives::FixedBytes<N>
+    where
+        Self: sealed::TruncateTo;
+}
+
+impl<T> CompatTruncate for T {
+    fn compat_truncate<U>(self) -> U
+    where
+        Self: sealed::CompatTruncate<U>,
+    {
+        sealed::CompatTruncate::compat_truncate(self)
+    }
+    fn compat_truncate_to<const N: usize>(self) -> alloy_primitives::FixedBytes<N>
+    where
+        Self: sealed::TruncateTo,
+    {
+        const { assert!(N <= <T as sealed::TruncateTo>::LEN) }
+        let bytes = sealed::TruncateTo::bytes(&self);
+        alloy_primitives::FixedBytes::from_slice(&bytes[bytes.len() - N..])
+    }
+}
+
+/// Convert a smaller [`ethereum_types`] type to a larger [`alloy_primitives`] type,
+/// or vice-versa, by left-padding with zeroes.
+///
+/// An `M`-byte value is extended to `N` bytes by placing it in the rightmost (low-order) bytes,
+/// `N - M..N`, with zeroes above.
+/// So an address is extended to a 32-byte word by placing it in bytes `12..32`,
+/// as in ABI encoding and storage layouts.
+///
+/// Implemented from 64, 128 and 256 bits to each larger width,
+/// and from an address to 256 bits.
+/// An address may also be extended to a `U256`, treating it as a big-endian integer,
+/// and [`TryCompat`] goes back, failing if any of the top 96 bits are set.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatExtend as _;
+///
+/// let address = alloy::address!("deadbeefdeadbeefdeadbeefdeadbeef00000000");
+/// let word: eth::H256 = address.compat_extend();
+/// assert_eq!(word[..12], [0; 12]);
+/// assert_eq!(word[12..], address[..]);
+/// ```
+pub trait CompatExtend {
+    fn compat_extend<T>(self) -> T
+    where
+        Self: sealed::CompatExtend<T>;
+}
+
+impl<T> CompatExtend for T {
+    fn compat_extend<U>(self) -> U
+    where
+        Self: sealed::CompatExtend<U>,
+    {
+        sealed::CompatExtend::compat_extend(self)
+    }
+}
+
+/// [`Compat`] the value in an [`Option`], or return the target's [`Default`] for [`None`].
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatOrDefault as _;
+///
+/// let balance = None::<alloy::U256>;
+/// assert_eq!(balance.compat_or_default::<eth::U256>(), eth::U256::zero());
+/// ```
+pub trait CompatOrDefault {
+    type Item;
+    fn compat_or_default<T>(self) -> T
+    where
+        Self::Item: sealed::Compat<T>,
+        T: Default;
+}
+
+impl<T> CompatOrDefault for Option<T> {
+    type Item = T;
+    fn compat_or_default<U>(self) -> U
+    where
+        T: sealed::Compat<U>,
+        U: Default,
+    {
+        self.map(sealed::Compat::compat).unwrap_or_default()
+    }
+}
+
+/// Convert the hash of an [`alloy_primitives::Sealed`] value.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatSeal as _;
+///
+/// let header = alloy::Sealed::new_unchecked("header", alloy::B256::repeat_byte(0xde));
+/// assert_eq!(header.compat_seal(), eth::H256::repeat_byte(0xde));
+/// ```
+pub trait CompatSeal {
+    fn compat_seal(&self) -> ethereum_types::H256;
+}
+
+impl<T> CompatSeal for alloy_primitives::Sealed<T> {
+    #[inline]
+    fn compat_seal(&self) -> ethereum_types::H256 {
+        sealed::Compat::compat(self.seal())
+    }
+}
+
+/// Fallibly convert between an [`ethereum_types`] type and an [`alloy_primitives`] type.
+///
+/// This is implemented for narrowing integer conversions,
+/// reassembling signatures and logs, and from byte slices to fixed bytes.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::{CompatError, TryCompat as _};
+///
+/// let small = alloy::U256::from(1);
+/// assert_eq!(small.try_compat::<eth::U64>(), Ok(eth::U64::one()));
+///
+/// let big = alloy::U256::MAX;
+/// assert_eq!(big.try_compat::<eth::U64>(), Err(CompatError::Overflow));
+/// ```
+pub trait TryCompat {
+    fn try_compat<T>(self) -> Result<T, CompatError>
+    where
+        Self: sealed::TryCompat<T>;
+}
+
+impl<T> TryCompat for T {
+    fn try_compat<U>(self) -> Result<U, CompatError>
+    where
+        Self: sealed::TryCompat<U>,
+    {
+        sealed::TryCompat::try_compat(self)
+    }
+}
+
+/// Convert between integers, saturating at the numeric bounds of the target type.
+///
+/// This is implemented for narrowing integer conversions.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatSaturating as _;
+///
+/// assert_eq!(alloy::U256::MAX.compat_saturating::<eth::U64>(), eth::U64::MAX);
+/// ```
+pub trait CompatSaturating {
+    fn compat_saturating<T>(self) -> T
+    where
+        Self: sealed::CompatSaturating<T>;
+}
+
+impl<T> CompatSaturating for T {
+    fn compat_saturating<U>(self) -> U
+    where
+        Self: sealed::CompatSaturating<U>,
+    {
+        sealed::CompatSaturating::compat_saturating(self)
+    }
+}
+
+/// Convert between integers, keeping only the low bits.
+///
+/// This is implemented for narrowing integer conversions,
+/// and is reduction modulo `2^N`, where `N` is the width of the target type.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatWrapping as _;
+///
+/// let word = alloy::U256::from(u64::MAX) + alloy::U256::from(2);
+/// assert_eq!(word.compat_wrapping::<eth::U64>(), eth::U64::one());
+/// ```
+pub trait CompatWrapping {
+    fn compat_wrapping<T>(self) -> T
+    where
+        Self: sealed::CompatWrapping<T>;
+}
+
+impl<T> CompatWrapping for T {
+    fn compat_wrapping<U>(self) -> U
+    where
+        Self: sealed::CompatWrapping<U>,
+    {
+        sealed::CompatWrapping::compat_wrapping(self)
+    }
+}
+
+/// Convert between integers and fixed bytes of the same width, choosing the byte order.
+///
+/// [`Compat`] is always big-endian, matching [`ethereum_types::BigEndianHash`],
+/// so [`compat_be`](CompatEndian::compat_be) is the same as [`compat`](Compat::compat).
+/// [`compat_le`](CompatEndian::compat_le) places the least significant byte first.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::CompatEndian as _;
+///
+/// let one = alloy::U256::from(1);
+/// assert_eq!(one.compat_be::<eth::H256>()[31], 1);
+/// assert_eq!(one.compat_le::<eth::H256>()[0], 1);
+/// ```
+///
+/// 256-bit fixed bytes from either crate may also be split into `[u64; 4]` or `[u32; 8]` words.
+/// With [`compat_be`](CompatEndian::compat_be), the first word is the first bytes, read big-endian.
+/// With [`compat_le`](CompatEndian::compat_le), the first word is the first bytes, read little-endian,
+/// so the words are the limbs of the little-endian integer.
+///
+/// ```
+/// # use alloy_primitives as alloy;
+/// use alloy_compat::CompatEndian as _;
+///
+/// let hash = alloy::b256!("0000000000000001000000000000000000000000000000000000000000000000");
+/// assert_eq!(hash.compat_be::<[u64; 4]>(), [1, 0, 0, 0]);
+/// assert_eq!(hash.compat_le::<[u64; 4]>(), [1 << 56, 0, 0, 0]);
+/// ```
+pub trait CompatEndian {
+    fn compat_be<T>(self) -> T
+    where
+        Self: sealed::CompatEndian<T>;
+    fn compat_le<T>(self) -> T
+    where
+        Self: sealed::CompatEndian<T>;
+}
+
+impl<T> CompatEndian for T {
+    fn compat_be<U>(self) -> U
+    where
+        Self: sealed::CompatEndian<U>,
+    {
+        sealed::CompatEndian::compat_be(self)
+    }
+    fn compat_le<U>(self) -> U
+    where
+        Self: sealed::CompatEndian<U>,
+    {
+        sealed::CompatEndian::compat_le(self)
+    }
+}
+
+/// Convert a [`Vec`] in place, reusing its allocation.
+///
+/// This is only implemented for types with the same layout,
+/// such as [`alloy_primitives::B256`] and [`ethereum_types::H256`].
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let alloy = vec![alloy::B256::ZERO; 3];
+/// let ptr = alloy.as_ptr();
+/// let eth: Vec<eth::H256> = alloy_compat::compat_vec(alloy);
+/// assert_eq!(eth.as_ptr().cast(), ptr);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compat_vec<T, U>(v: alloc::vec::Vec<T>) -> alloc::vec::Vec<U>
+where
+    T: sealed::SameLayout<U>,
+{
+    const {
+        assert!(core::mem::size_of::<T>() == core::mem::size_of::<U>());
+        assert!(core::mem::align_of::<T>() == core::mem::align_of::<U>());
+    }
+    let mut v = core::mem::ManuallyDrop::new(v);
+    // SAFETY:
+    // - the allocation came from a `Vec`, with the same allocator.
+    // - `T` and `U` have the same size and alignment, so `len` and `capacity` are still correct.
+    // - `T` and `U` have the same valid bit patterns, so every element is a valid `U`.
+    // - `v` is not dropped, so the allocation is owned by the returned `Vec`.
+    unsafe { alloc::vec::Vec::from_raw_parts(v.as_mut_ptr().cast::<U>(), v.len(), v.capacity()) }
+}
+
+/// Reinterpret a slice without copying.
+///
+/// This is only implemented for types with the same layout,
+/// such as [`alloy_primitives::Address`] and [`ethereum_types::Address`],
+/// which is checked at compile time.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let alloy = [alloy::Address::ZERO; 3];
+/// let eth: &[eth::Address] = alloy_compat::compat_slice(&alloy);
+/// assert_eq!(eth, [eth::Address::zero(); 3]);
+/// ```
+pub fn compat_slice<T, U>(s: &[T]) -> &[U]
+where
+    T: sealed::SameLayout<U>,
+{
+    const {
+        assert!(core::mem::size_of::<T>() == core::mem::size_of::<U>());
+        assert!(core::mem::align_of::<T>() == core::mem::align_of::<U>());
+    }
+    // SAFETY:
+    // - `T` and `U` have the same size and alignment, so the pointer is aligned and `len` is correct.
+    // - `T` and `U` have the same valid bit patterns, so every element is a valid `U`.
+    // - the returned slice borrows from `s`.
+    unsafe { core::slice::from_raw_parts(s.as_ptr().cast::<U>(), s.len()) }
+}
+
+/// Convert each element of a [`Vec`] in parallel, using [`rayon`].
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let alloy = vec![alloy::B256::ZERO; 1000];
+/// let eth: Vec<eth::H256> = alloy_compat::compat_par(alloy);
+/// assert_eq!(eth, vec![eth::H256::zero(); 1000]);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn compat_par<T, U>(v: Vec<T>) -> Vec<U>
+where
+    T: sealed::Compat<U> + Send,
+    U: Send,
+{
+    use rayon::iter::{IntoParallelIterator as _, ParallelIterator as _};
+    v.into_par_iter().map(sealed::Compat::compat).collect()
+}
+
+/// Convert each element of `src` into `dst`, without allocating.
+///
+/// Returns [`CompatError::WrongLength`] if the lengths differ,
+/// leaving `dst` untouched.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let alloy = [alloy::B256::repeat_byte(0xde); 2];
+/// let mut eth = [eth::H256::zero(); 2];
+/// alloy_compat::compat_into_slice(&alloy, &mut eth).unwrap();
+/// assert_eq!(eth, [eth::H256::repeat_byte(0xde); 2]);
+/// ```
+pub fn compat_into_slice<T, U>(src: &[T], dst: &mut [U]) -> Result<(), CompatError>
+where
+    T: Clone + sealed::Compat<U>,
+{
+    if src.len() != dst.len() {
+        return Err(CompatError::WrongLength {
+            expected: dst.len(),
+            got: src.len(),
+        });
+    }
+    for (src, dst) in src.iter().zip(dst) {
+        *dst = src.clone().compat();
+    }
+    Ok(())
+}
+
+/// Parse a hex string, with or without a `0x` prefix, into fixed bytes from either crate.
+///
+/// This uses [`alloy_primitives`]'s parsing,
+/// so errors are returned as [`CompatError::Parse`].
+///
+/// ```
+/// # use ethereum_types as eth;
+/// use alloy_compat::{try_compat_from_hex, CompatError};
+///
+/// let hash: eth::H256 = try_compat_from_hex(
+///     "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+/// ).unwrap();
+/// assert_eq!(hash[0], 0x56);
+///
+/// assert!(matches!(try_compat_from_hex::<eth::H256>("0xdead"), Err(CompatError::Parse(_))));
+/// ```
+pub fn try_compat_from_hex<T>(s: &str) -> Result<T, CompatError>
+where
+    T: sealed::FromHex,
+{
+    T::from_hex(s).map_err(CompatError::Parse)
+}
+
+/// Concatenate addresses into packed bytes, 20 bytes each, without padding.
+///
+/// Accepts either crate's address type.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let addresses = [alloy::Address::repeat_byte(0xde), alloy::Address::repeat_byte(0xad)];
+/// let packed = alloy_compat::pack_addresses(&addresses);
+/// assert_eq!(packed.len(), 40);
+///
+/// let eth: Vec<eth::Address> = alloy_compat::unpack_addresses(&packed).unwrap();
+/// assert_eq!(eth, [eth::Address::repeat_byte(0xde), eth::Address::repeat_byte(0xad)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn pack_addresses<A>(addresses: &[A]) -> alloc::vec::Vec<u8>
+where
+    A: sealed::Address,
+{
+    addresses.iter().flat_map(A::to_bytes).collect()
+}
+
+/// Split packed bytes into addresses, 20 bytes each.
+///
+/// Returns [`CompatError::WrongLength`] if the length isn't a multiple of 20,
+/// with `expected` rounded up to the next multiple.
+#[cfg(feature = "alloc")]
+pub fn unpack_addresses<A>(bytes: &[u8]) -> Result<alloc::vec::Vec<A>, CompatError>
+where
+    A: sealed::Address,
+{
+    let chunks = bytes.chunks_exact(20);
+    if !chunks.remainder().is_empty() {
+        return Err(CompatError::WrongLength {
+            expected: bytes.len().next_multiple_of(20),
+            got: bytes.len(),
+        });
+    }
+    Ok(chunks
+        .map(|chunk| A::from_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Generate an [`Arbitrary`](arbitrary::Arbitrary) value, and its [`Compat`] counterpart.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let mut u = arbitrary::Unstructured::new(&[0xde; 32]);
+/// let (alloy, eth) = alloy_compat::arbitrary_pair::<alloy::B256, eth::H256>(&mut u).unwrap();
+/// assert_eq!(alloy.as_slice(), eth.as_bytes());
+/// ```
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_pair<'a, T, U>(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<(T, U)>
+where
+    T: arbitrary::Arbitrary<'a> + Clone + sealed::Compat<U>,
+{
+    let t = T::arbitrary(u)?;
+    let u = t.clone().compat();
+    Ok((t, u))
+}
+
+/// Convert an arbitrary-width [`Uint`] to an [`ethereum_types`] integer,
+/// returning [`None`] if it does not fit.
+///
+/// `ethereum_types` only has [`U64`](ethereum_types::U64), [`U128`](ethereum_types::U128),
+/// [`U256`](ethereum_types::U256) and [`U512`](ethereum_types::U512),
+/// so other widths can't be [`Compat`].
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let packed = alloy::aliases::U40::from(0xdeadbeef_u64);
+/// let eth: eth::U64 = alloy_compat::uint_to_eth(packed).unwrap();
+/// assert_eq!(eth, eth::U64::from(0xdeadbeef_u64));
+/// ```
+pub fn uint_to_eth<T, const BITS: usize, const LIMBS: usize>(uint: Uint<BITS, LIMBS>) -> Option<T>
+where
+    T: sealed::EthUint,
+{
+    T::checked_from_limbs(uint.as_limbs())
+}
+
+/// Convert an [`ethereum_types`] integer to an arbitrary-width [`Uint`],
+/// returning [`None`] if it does not fit.
+///
+/// See [`uint_to_eth`].
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let eth = eth::U64::from(0xdeadbeef_u64);
+/// let packed: alloy::aliases::U40 = alloy_compat::uint_from_eth(eth).unwrap();
+/// assert_eq!(packed, alloy::aliases::U40::from(0xdeadbeef_u64));
+/// assert_eq!(alloy_compat::uint_from_eth::<40, 1, _>(eth::U64::MAX), None);
+/// ```
+pub fn uint_from_eth<const BITS: usize, const LIMBS: usize, T>(eth: T) -> Option<Uint<BITS, LIMBS>>
+where
+    T: sealed::EthUint,
+{
+    Uint::checked_from_limbs_slice(eth.limbs())
+}
+
+mod sealed {
+    use crate::CompatError;
+    use alloy_primitives::FixedBytes;
+    use ethereum_types::BigEndianHash as _;
+
+    pub trait Compat<T> {
+        fn compat(self) -> T;
+    }
+
+    pub trait TryCompat<T> {
+        fn try_compat(self) -> Result<T, CompatError>;
+    }
+
+    pub trait CompatSaturating<T> {
+        fn compat_saturating(self) -> T;
+    }
+
+    pub trait CompatWrapping<T> {
+        fn compat_wrapping(self) -> T;
+    }
+
+    pub trait CompatTruncate<T> {
+        fn compat_truncate(self) -> T;
+    }
+
+    pub trait CompatExtend<T> {
+        fn compat_extend(self) -> T;
+    }
+
+    /// Fixed bytes which may be truncated to any smaller width.
+    pub trait TruncateTo {
+        const LEN: usize;
+        fn bytes(&self) -> &[u8];
+    }
+
+    impl TruncateTo for ethereum_types::H256 {
+        const LEN: usize = 32;
+        #[inline]
+        fn bytes(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    pub trait CompatEndian<T> {
+        fn compat_be(self) -> T;
+        fn compat_le(self) -> T;
+    }
+
+    /// # Safety
+    /// `Self` and `T` must have the same layout and valid bit patterns,
+    /// and [`Compat::compat`] must be equivalent to a transmute.
+    pub unsafe trait SameLayout<T>: Compat<T> {}
+
+    /// Implement [`SameLayout`] both ways,
+    /// failing compilation if the sizes or alignments differ.
+    macro_rules! same_layout {
+        ($(#[$meta:meta])* $alloy:ty, $eth:ty) => {
+            $(#[$meta])*
+            unsafe impl SameLayout<$eth> for $alloy {}
+            $(#[$meta])*
+            unsafe impl SameLayout<$alloy> for $eth {}
+            $(#[$meta])*
+            const _: () = {
+                assert!(core::mem::size_of::<$alloy>() == core::mem::size_of::<$eth>());
+                assert!(core::mem::align_of::<$alloy>() == core::mem::align_of::<$eth>());
+            };
+        };
+    }
+
+    /// Fixed bytes which may be parsed from hex by [`alloy_primitives`].
+    pub trait FromHex: Sized {
+        fn from_hex(s: &str) -> Result<Self, alloy_primitives::hex::FromHexError>;
+    }
+
+    impl<const N: usize> FromHex for FixedBytes<N> {
+        #[inline]
+        fn from_hex(s: &str) -> Result<Self, alloy_primitives::hex::FromHexError> {
+            s.parse()
+        }
+    }
+    impl FromHex for alloy_primitives::Address {
+        #[inline]
+        fn from_hex(s: &str) -> Result<Self, alloy_primitives::hex::FromHexError> {
+            s.parse()
+        }
+    }
+    impl FromHex for alloy_primitives::Bloom {
+        #[inline]
+        fn from_hex(s: &str) -> Result<Self, alloy_primitives::hex::FromHexError> {
+            s.parse()
+        }
+    }
+
+    /// Parse as the [`alloy_primitives`] type of the same width, then convert.
+    macro_rules! from_hex {
+        ($($alloy:ty : $eth:ty);* $(;)?) => {
+            $(
+                impl FromHex for $eth {
+                    #[inline]
+                    fn from_hex(s: &str) -> Result<Self, alloy_primitives::hex::FromHexError> {
+                        <$alloy as FromHex>::from_hex(s).map(Compat::compat)
+                    }
+                }
+            )*
+        };
+    }
+
+    from_hex! {
+        FixedBytes<4> : ethereum_types::H32;
+        FixedBytes<8> : ethereum_types::H64;
+        FixedBytes<16> : ethereum_types::H128;
+        alloy_primitives::Address : ethereum_types::H160;
+        FixedBytes<32> : ethereum_types::H256;
+        FixedBytes<33> : ethereum_types::H264;
+        FixedBytes<64> : ethereum_types::H512;
+        FixedBytes<65> : ethereum_types::H520;
+        alloy_primitives::Bloom : ethereum_types::Bloom;
+    }
+
+    /// [`alloy_primitives::Address`] or [`ethereum_types::Address`].
+    #[cfg(feature = "alloc")]
+    pub trait Address {
+        fn to_bytes(&self) -> [u8; 20];
+        fn from_bytes(bytes: [u8; 20]) -> Self;
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Address for alloy_primitives::Address {
+        #[inline]
+        fn to_bytes(&self) -> [u8; 20] {
+            self.into_array()
+        }
+        #[inline]
+        fn from_bytes(bytes: [u8; 20]) -> Self {
+            Self(FixedBytes(bytes))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Address for ethereum_types::Address {
+        #[inline]
+        fn to_bytes(&self) -> [u8; 20] {
+            self.0
+        }
+        #[inline]
+        fn from_bytes(bytes: [u8; 20]) -> Self {
+            Self(bytes)
+        }
+    }
+
+    /// The [`ethereum_types`] type an [`alloy_primitives`] type usually converts to.
+    #[cfg(feature = "test-util")]
+    pub trait Counterpart: Compat<Self::Eth> + Sized {
+        type Eth: Compat<Self>;
+    }
+
+    /// The [`alloy_primitives`] type an [`ethereum_types`] type usually converts to.
+    #[cfg(feature = "serde")]
+    pub trait AlloyCounterpart: Compat<Self::Alloy> + Sized {
+        type Alloy: Compat<Self>;
+    }
+
+    #[cfg(feature = "serde")]
+    macro_rules! alloy_counterpart {
+        ($($eth:path => $alloy:ty);* $(;)?) => {
+            $(
+                impl AlloyCounterpart for $eth {
+                    type Alloy = $alloy;
+                }
+            )*
+        };
+    }
+
+    // `H160` is `ethereum_types::Address`, so maps to `alloy_primitives::Address`
+    #[cfg(feature = "serde")]
+    alloy_counterpart! {
+        ethereum_types::H32 => alloy_primitives::aliases::B32;
+        ethereum_types::H64 => alloy_primitives::B64;
+        ethereum_types::H128 => alloy_primitives::B128;
+        ethereum_types::H160 => alloy_primitives::Address;
+        ethereum_types::H256 => alloy_primitives::B256;
+        ethereum_types::H264 => alloy_primitives::FixedBytes<33>;
+        ethereum_types::H512 => alloy_primitives::B512;
+        ethereum_types::H520 => alloy_primitives::FixedBytes<65>;
+        ethereum_types::Bloom => alloy_primitives::Bloom;
+        ethereum_types::U64 => alloy_primitives::U64;
+        ethereum_types::U128 => alloy_primitives::U128;
+        ethereum_types::U256 => alloy_primitives::U256;
+        ethereum_types::U512 => alloy_primitives::U512;
+    }
+
+    /// An [`alloy_primitives`] type, or a container of them,
+    /// as the source of [`FromAlloy`](crate::FromAlloy).
+    pub trait Alloy {}
+
+    /// An [`ethereum_types`] type, or a container of them,
+    /// as the source of [`FromEth`](crate::FromEth).
+    pub trait Eth {}
+
+    /// The types on each side, which also convert through shared references by cloning.
+    ///
+    /// So e.g. `Option<&T>` converts through the [`Option`] impl,
+    /// which a dedicated `Option<&T>` impl would overlap with.
+    macro_rules! side {
+        ($side:ident: $($ty:ty),* $(,)?) => {
+            $(
+                impl $side for $ty {}
+                impl $side for &$ty {}
+                impl<U> Compat<U> for &$ty
+                where
+                    $ty: Compat<U>,
+                {
+                    #[inline]
+                    fn compat(self) -> U {
+                        self.clone().compat()
+                    }
+                }
+            )*
+        };
+    }
+
+    side! {
+        Alloy:
+        alloy_primitives::aliases::B32,
+        alloy_primitives::B64,
+        alloy_primitives::B128,
+        alloy_primitives::FixedBytes<20>,
+        alloy_primitives::B256,
+        alloy_primitives::FixedBytes<33>,
+        alloy_primitives::B512,
+        alloy_primitives::FixedBytes<65>,
+        alloy_primitives::Address,
+        alloy_primitives::Bloom,
+        alloy_primitives::U64,
+        alloy_primitives::U128,
+        alloy_primitives::aliases::U160,
+        alloy_primitives::U256,
+        alloy_primitives::U512,
+        alloy_primitives::I64,
+        alloy_primitives::I128,
+        alloy_primitives::I256,
+        alloy_primitives::aliases::I512,
+        alloy_primitives::PrimitiveSignature,
+    }
+
+    side! {
+        Eth:
+        ethereum_types::H32,
+        ethereum_types::H64,
+        ethereum_types::H128,
+        ethereum_types::H160,
+        ethereum_types::H256,
+        ethereum_types::H264,
+        ethereum_types::H512,
+        ethereum_types::H520,
+        ethereum_types::Bloom,
+        ethereum_types::U64,
+        ethereum_types::U128,
+        ethereum_types::U256,
+        ethereum_types::U512,
+    }
+
+    /// Both sides, for a container which has a [`Compat`] impl,
+    /// as long as everything it contains is on that side.
+    macro_rules! container_side {
+        ($($(#[$meta:meta])* impl<$($param:ident),*> for $ty:ty;)*) => {
+            $(
+                $(#[$meta])*
+                impl<$($param: Alloy),*> Alloy for $ty {}
+                $(#[$meta])*
+                impl<$($param: Eth),*> Eth for $ty {}
+            )*
+        };
+    }
+
+    container_side! {
+        impl<T> for Option<T>;
+        impl<T> for core::ops::Range<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::vec::Vec<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for &[T];
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::collections::VecDeque<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::collections::LinkedList<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::boxed::Box<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::sync::Arc<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::rc::Rc<T>;
+        #[cfg(feature = "alloc")]
+        impl<T> for alloc::collections::BTreeSet<T>;
+        #[cfg(feature = "alloc")]
+        impl<K, V> for alloc::collections::BTreeMap<K, V>;
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T: Alloy + Clone> Alloy for alloc::borrow::Cow<'_, T> {}
+    #[cfg(feature = "alloc")]
+    impl<T: Eth + Clone> Eth for alloc::borrow::Cow<'_, T> {}
+    impl<T: Alloy, E> Alloy for Result<T, E> {}
+    impl<T: Eth, E> Eth for Result<T, E> {}
+    impl<T: Alloy, const N: usize> Alloy for [T; N] {}
+    impl<T: Eth, const N: usize> Eth for [T; N] {}
+    #[cfg(feature = "std")]
+    impl<T: Alloy, S> Alloy for std::collections::HashSet<T, S> {}
+    #[cfg(feature = "std")]
+    impl<T: Eth, S> Eth for std::collections::HashSet<T, S> {}
+    #[cfg(feature = "std")]
+    impl<K: Alloy, V: Alloy, S> Alloy for std::collections::HashMap<K, V, S> {}
+    #[cfg(feature = "std")]
+    impl<K: Eth, V: Eth, S> Eth for std::collections::HashMap<K, V, S> {}
+    #[cfg(feature = "indexmap")]
+    impl<K: Alloy, V: Alloy, S> Alloy for indexmap::IndexMap<K, V, S> {}
+    #[cfg(feature = "indexmap")]
+    impl<K: Eth, V: Eth, S> Eth for indexmap::IndexMap<K, V, S> {}
+    #[cfg(feature = "smallvec")]
+    impl<A: smallvec::Array> Alloy for smallvec::SmallVec<A> where A::Item: Alloy {}
+    #[cfg(feature = "smallvec")]
+    impl<A: smallvec::Array> Eth for smallvec::SmallVec<A> where A::Item: Eth {}
+
+    /// An [`ethereum_types`] integer.
+    pub trait EthUint: Sized {
+        /// Little-endian limbs.
+        fn limbs(&self) -> &[u64];
+        fn checked_from_limbs(limbs: &[u64]) -> Option<Self>;
+        /// Discards the high limbs.
+        fn wrapping_from_limbs(limbs: &[u64]) -> Self;
+    }
+
+    macro_rules! compat_fixed_bytes {
+        ($(
+            $(#[$meta:meta])* $alloy:path : $eth:path => $to_eth:ident, $to_alloy:ident
+        );* $(;)?) => {
+            $(
+                $(#[$meta])*
+                impl Compat<$eth> for $alloy {
+                    #[inline]
+                    fn compat(self) -> $eth {
+                        crate::convert::$to_eth(self)
+                    }
+                }
+                $(#[$meta])*
+                impl Compat<$alloy> for $eth {
+                    #[inline]
+                    fn compat(self) -> $alloy {
+                        crate::convert::$to_alloy(self)
+                    }
+                }
+                #[cfg(feature = "test-util")]
+                $(#[$meta])*
+                impl Counterpart for $alloy {
+                    type Eth = $eth;
+                }
+                // SAFETY: both are `[u8; N]` newtypes
+                same_layout!($(#[$meta])* $alloy, $eth);
+                $(#[$meta])*
+                impl TryCompat<$alloy> for &[u8] {
+                    #[inline]
+                    fn try_compat(self) -> Result<$alloy, CompatError> {
+                        <$alloy>::try_from(self).map_err(|_| CompatError::WrongLength {
+                            expected: core::mem::size_of::<$alloy>(),
+                            got: self.len(),
+                        })
+                    }
+                }
+                $(#[$meta])*
+                impl TryCompat<$eth> for &[u8] {
+                    #[inline]
+                    fn try_compat(self) -> Result<$eth, CompatError> {
+                        TryCompat::<$alloy>::try_compat(self).map(Compat::compat)
+                    }
+                }
+                $(#[$meta])*
+                #[cfg(feature = "alloc")]
+                impl TryCompat<$alloy> for alloc::vec::Vec<u8> {
+                    #[inline]
+                    fn try_compat(self) -> Result<$alloy, CompatError> {
+                        self.as_slice().try_compat()
+                    }
+                }
+                $(#[$meta])*
+                #[cfg(feature = "alloc")]
+                impl TryCompat<$eth> for alloc::vec::Vec<u8> {
+                    #[inline]
+                    fn try_compat(self) -> Result<$eth, CompatError> {
+                        self.as_slice().try_compat()
+                    }
+                }
+            )*
+        };
+    }
+
+    compat_fixed_bytes! {
+        alloy_primitives::aliases::B32 : ethereum_types::H32
+            => alloy_b32_to_eth_h32, eth_h32_to_alloy_b32;
+        alloy_primitives::B64 : ethereum_types::H64
+            => alloy_b64_to_eth_h64, eth_h64_to_alloy_b64;
+        alloy_primitives::B128 : ethereum_types::H128
+            => alloy_b128_to_eth_h128, eth_h128_to_alloy_b128;
+        #[allow(deprecated)] alloy_primitives::B160 : ethereum_types::H160
+            => alloy_fixed_bytes_20_to_eth_h160, eth_h160_to_alloy_fixed_bytes_20;
+        alloy_primitives::B256 : ethereum_types::H256
+            => alloy_b256_to_eth_h256, eth_h256_to_alloy_b256;
+        // `alloy_primitives` has no `B264` alias
+        alloy_primitives::FixedBytes<33> : ethereum_types::H264
+            => alloy_fixed_bytes_33_to_eth_h264, eth_h264_to_alloy_fixed_bytes_33;
+        alloy_primitives::B512 : ethereum_types::H512
+            => alloy_b512_to_eth_h512, eth_h512_to_alloy_b512;
+        // `alloy_primitives` has no `B520` alias
+        alloy_primitives::FixedBytes<65> : ethereum_types::H520
+            => alloy_fixed_bytes_65_to_eth_h520, eth_h520_to_alloy_fixed_bytes_65;
+    }
+
+    macro_rules! compat_uint {
+        ($(
+            $(#[$meta:meta])* $alloy:path : $eth:path => $to_eth:ident, $to_alloy:ident
+        );* $(;)?) => {
+            $(
+                // both are little-endian `u64` limbs, regardless of target endianness,
+                // so copy the limbs unless asked not to.
+                $(#[$meta])*
+                impl Compat<$eth> for $alloy {
+                    #[inline]
+                    fn compat(self) -> $eth {
+                        if cfg!(alloy_compat_uint_bytes) {
+                            <$eth>::from_little_endian(&self.to_le_bytes::<{ <$alloy>::BYTES }>())
+                        } else {
+                            crate::convert::$to_eth(self)
+                        }
+                    }
+                }
+                $(#[$meta])*
+                impl Compat<$alloy> for $eth {
+                    #[inline]
+                    fn compat(self) -> $alloy {
+                        if cfg!(alloy_compat_uint_bytes) {
+                            <$alloy>::from_le_bytes(self.to_little_endian())
+                        } else {
+                            crate::convert::$to_alloy(self)
+                        }
+                    }
+                }
+                #[cfg(feature = "test-util")]
+                $(#[$meta])*
+                impl Counterpart for $alloy {
+                    type Eth = $eth;
+                }
+                // SAFETY: both are `[u64; N]` newtypes
+                same_layout!($(#[$meta])* $alloy, $eth);
+                $(#[$meta])*
+                impl EthUint for $eth {
+                    #[inline]
+                    fn limbs(&self) -> &[u64] {
+                        &self.0
+                    }
+                    #[inline]
+                    fn checked_from_limbs(limbs: &[u64]) -> Option<Self> {
+                        let this = Self::wrapping_from_limbs(limbs);
+                        let hi = limbs.get(this.0.len()..).unwrap_or_default();
+                        hi.iter().all(|it| *it == 0).then_some(this)
+                    }
+                    #[inline]
+                    fn wrapping_from_limbs(limbs: &[u64]) -> Self {
+                        let mut this = Self::zero();
+                        let len = limbs.len().min(this.0.len());
+                        this.0[..len].copy_from_slice(&limbs[..len]);
+                        this
+                    }
+                }
+            )*
+        };
+    }
+
+    compat_uint! {
+        alloy_primitives::U64 : ethereum_types::U64 => alloy_u64_to_eth_u64, eth_u64_to_alloy_u64;
+        alloy_primitives::U128 : ethereum_types::U128 => alloy_u128_to_eth_u128, eth_u128_to_alloy_u128;
+        alloy_primitives::U256 : ethereum_types::U256 => alloy_u256_to_eth_u256, eth_u256_to_alloy_u256;
+        alloy_primitives::U512 : ethereum_types::U512 => alloy_u512_to_eth_u512, eth_u512_to_alloy_u512;
+    }
+
+    /// Signed integers, reinterpreting the two's complement bit pattern.
+    ///
+    /// This is not a numeric conversion: [`alloy_primitives::I256::MINUS_ONE`]
+    /// is [`ethereum_types::U256::MAX`].
+    macro_rules! compat_signed {
+        ($(
+            $alloy_signed:path, $alloy_uint:path : $eth:path => $to_eth:ident, $to_alloy:ident
+        );* $(;)?) => {
+            $(
+                impl Compat<$eth> for $alloy_signed {
+                    #[inline]
+                    fn compat(self) -> $eth {
+                        crate::convert::$to_eth(self)
+                    }
+                }
+                impl Compat<$alloy_signed> for $eth {
+                    #[inline]
+                    fn compat(self) -> $alloy_signed {
+                        crate::convert::$to_alloy(self)
+                    }
+                }
+                /// A numeric conversion, failing if the value is not representable.
+                impl TryCompat<$alloy_signed> for $eth {
+                    #[inline]
+                    fn try_compat(self) -> Result<$alloy_signed, CompatError> {
+                        match self.bit(<$alloy_uint>::BITS - 1) {
+                            true => Err(CompatError::Overflow),
+                            false => Ok(Compat::compat(self)),
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    compat_signed! {
+        alloy_primitives::I64, alloy_primitives::U64 : ethereum_types::U64
+            => alloy_i64_to_eth_u64, eth_u64_to_alloy_i64;
+        alloy_primitives::I128, alloy_primitives::U128 : ethereum_types::U128
+            => alloy_i128_to_eth_u128, eth_u128_to_alloy_i128;
+        alloy_primitives::I256, alloy_primitives::U256 : ethereum_types::U256
+            => alloy_i256_to_eth_u256, eth_u256_to_alloy_i256;
+        alloy_primitives::aliases::I512, alloy_primitives::U512 : ethereum_types::U512
+            => alloy_i512_to_eth_u512, eth_u512_to_alloy_i512;
+    }
+
+    /// Lossless, since the widths match.
+    macro_rules! compat_primitive {
+        ($(
+            $primitive:ty : $alloy:path, $eth:path
+            => $alloy_to:ident, $to_alloy:ident, $eth_to:ident, $to_eth:ident
+        );* $(;)?) => {
+            $(
+                impl Compat<$primitive> for $alloy {
+                    #[inline]
+                    fn compat(self) -> $primitive {
+                        crate::convert::$alloy_to(self)
+                    }
+                }
+                impl Compat<$alloy> for $primitive {
+                    #[inline]
+                    fn compat(self) -> $alloy {
+                        crate::convert::$to_alloy(self)
+                    }
+                }
+                impl Compat<$primitive> for $eth {
+                    #[inline]
+                    fn compat(self) -> $primitive {
+                        crate::convert::$eth_to(self)
+                    }
+                }
+                impl Compat<$eth> for $primitive {
+                    #[inline]
+                    fn compat(self) -> $eth {
+                        crate::convert::$to_eth(self)
+                    }
+                }
+            )*
+        };
+    }
+
+    compat_primitive! {
+        u64 : alloy_primitives::U64, ethereum_types::U64
+            => alloy_u64_to_u64, u64_to_alloy_u64, eth_u64_to_u64, u64_to_eth_u64;
+        u128 : alloy_primitives::U128, ethereum_types::U128
+            => alloy_u128_to_u128, u128_to_alloy_u128, eth_u128_to_u128, u128_to_eth_u128;
+    }
+
+    /// Narrowing integer conversions.
+    macro_rules! narrow_uint {
+        ($($alloy_wide:path, $eth_wide:path => $alloy_narrow:path, $eth_narrow:path);* $(;)?) => {
+            $(
+                // fail if the high limbs are non-zero
+                impl TryCompat<$eth_narrow> for $alloy_wide {
+                    #[inline]
+                    fn try_compat(self) -> Result<$eth_narrow, CompatError> {
+                        EthUint::checked_from_limbs(self.as_limbs()).ok_or(CompatError::Overflow)
+                    }
+                }
+                impl TryCompat<$alloy_narrow> for $eth_wide {
+                    #[inline]
+                    fn try_compat(self) -> Result<$alloy_narrow, CompatError> {
+                        <$alloy_narrow>::checked_from_limbs_slice(self.limbs())
+                            .ok_or(CompatError::Overflow)
+                    }
+                }
+                impl CompatSaturating<$eth_narrow> for $alloy_wide {
+                    #[inline]
+                    fn compat_saturating(self) -> $eth_narrow {
+                        self.try_compat().unwrap_or(<$eth_narrow>::MAX)
+                    }
+                }
+                impl CompatSaturating<$alloy_narrow> for $eth_wide {
+                    #[inline]
+                    fn compat_saturating(self) -> $alloy_narrow {
+                        self.try_compat().unwrap_or(<$alloy_narrow>::MAX)
+                    }
+                }
+                impl CompatWrapping<$eth_narrow> for $alloy_wide {
+                    #[inline]
+                    fn compat_wrapping(self) -> $eth_narrow {
+                        EthUint::wrapping_from_limbs(self.as_limbs())
+                    }
+                }
+                impl CompatWrapping<$alloy_narrow> for $eth_wide {
+                    #[inline]
+                    fn compat_wrapping(self) -> $alloy_narrow {
+                        <$alloy_narrow>::wrapping_from_limbs_slice(self.limbs())
+                    }
+                }
+            )*
+        };
+    }
+
+    narrow_uint! {
+        alloy_primitives::U128, ethereum_types::U128 => alloy_primitives::U64, ethereum_types::U64;
+        alloy_primitives::U256, ethereum_types::U256 => alloy_primitives::U64, ethereum_types::U64;
+        alloy_primitives::U256, ethereum_types::U256 => alloy_primitives::U128, ethereum_types::U128;
+        alloy_primitives::U512, ethereum_types::U512 => alloy_primitives::U64, ethereum_types::U64;
+        alloy_primitives::U512, ethereum_types::U512 => alloy_primitives::U128, ethereum_types::U128;
+        alloy_primitives::U512, ethereum_types::U512 => alloy_primitives::U256, ethereum_types::U256;
+    }
+
+    // `ethereum_types` has no 160-bit integer, so zero-extend
+    impl Compat<ethereum_types::U256> for alloy_primitives::aliases::U160 {
+        #[inline]
+        fn compat(self) -> ethereum_types::U256 {
+            crate::convert::alloy_u160_to_eth_u256(self)
+        }
+    }
+    impl TryCompat<alloy_primitives::aliases::U160> for ethereum_types::U256 {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::aliases::U160, CompatError> {
+            alloy_primitives::Uint::checked_from_limbs_slice(self.limbs())
+                .ok_or(CompatError::Overflow)
+        }
+    }
+
+    /// Little-endian limbs to little-endian bytes.
+    #[inline]
+    fn le_bytes<const N: usize>(limbs: &[u64]) -> [u8; N] {
+        let mut bytes = [0; N];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+            chunk.copy_from_slice(&limb.to_le_bytes())
+        }
+        bytes
+    }
+
+    /// Little-endian bytes to little-endian limbs.
+    #[inline]
+    fn le_limbs<const L: usize>(bytes: &[u8]) -> [u64; L] {
+        let mut limbs = [0; L];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap())
+        }
+        limbs
+    }
+
+    /// Integers and fixed bytes of the same width, in big-endian byte order.
+    ///
+    /// These match [`ethereum_types::BigEndianHash`], e.g. `H256::from_uint(&u.compat())`.
+    /// [`CompatEndian`] additionally offers little-endian byte order.
+    ///
+    /// The big-endian conversions call the functions in [`crate::convert`].
+    macro_rules! compat_uint_fixed_bytes {
+        ($(
+            $alloy_uint:path, $alloy_bytes:path : $eth_uint:path, $eth_bytes:path
+            => $alloy_to_eth:ident, $eth_to_alloy:ident, $eth_to_alloy_bytes:ident, $alloy_to_eth_uint:ident
+        );* $(;)?) => {
+            $(
+                impl Compat<$eth_bytes> for $alloy_uint {
+                    #[inline]
+                    fn compat(self) -> $eth_bytes {
+                        crate::convert::$alloy_to_eth(self)
+                    }
+                }
+                impl Compat<$alloy_uint> for $eth_bytes {
+                    #[inline]
+                    fn compat(self) -> $alloy_uint {
+                        crate::convert::$eth_to_alloy(self)
+                    }
+                }
+                impl Compat<$eth_uint> for $alloy_bytes {
+                    #[inline]
+                    fn compat(self) -> $eth_uint {
+                        crate::convert::$alloy_to_eth_uint(self)
+                    }
+                }
+                impl Compat<$alloy_bytes> for $eth_uint {
+                    #[inline]
+                    fn compat(self) -> $alloy_bytes {
+                        crate::convert::$eth_to_alloy_bytes(self)
+                    }
+                }
+                impl CompatEndian<$eth_bytes> for $alloy_uint {
+                    #[inline]
+                    fn compat_be(self) -> $eth_bytes {
+                        self.compat()
+                    }
+                    #[inline]
+                    fn compat_le(self) -> $eth_bytes {
+                        $eth_bytes(le_bytes(self.as_limbs()))
+                    }
+                }
+                impl CompatEndian<$alloy_uint> for $eth_bytes {
+                    #[inline]
+                    fn compat_be(self) -> $alloy_uint {
+                        self.compat()
+                    }
+                    #[inline]
+                    fn compat_le(self) -> $alloy_uint {
+                        <$alloy_uint>::from_limbs(le_limbs(self.as_bytes()))
+                    }
+                }
+                impl CompatEndian<$eth_uint> for $alloy_bytes {
+                    #[inline]
+                    fn compat_be(self) -> $eth_uint {
+                        self.compat()
+                    }
+                    #[inline]
+                    fn compat_le(self) -> $eth_uint {
+                        $eth_uint(le_limbs(self.as_slice()))
+                    }
+                }
+                impl CompatEndian<$alloy_bytes> for $eth_uint {
+                    #[inline]
+                    fn compat_be(self) -> $alloy_bytes {
+                        self.compat()
+                    }
+                    #[inline]
+                    fn compat_le(self) -> $alloy_bytes {
+                        FixedBytes(le_bytes(self.limbs()))
+                    }
+                }
+            )*
+        };
+    }
+
+    /// Fixed bytes and arrays of words.
+    macro_rules! compat_words {
+        ($($bytes:ty : [$word:ty; $n:literal]);* $(;)?) => {
+            $(
+                impl CompatEndian<[$word; $n]> for $bytes {
+                    #[inline]
+                    fn compat_be(self) -> [$word; $n] {
+                        let mut words = [0; $n];
+                        let chunks = AsRef::<[u8]>::as_ref(&self).chunks_exact(size_of::<$word>());
+                        for (word, chunk) in words.iter_mut().zip(chunks) {
+                            *word = <$word>::from_be_bytes(chunk.try_into().unwrap())
+                        }
+                        words
+                    }
+                    #[inline]
+                    fn compat_le(self) -> [$word; $n] {
+                        let mut words = [0; $n];
+                        let chunks = AsRef::<[u8]>::as_ref(&self).chunks_exact(size_of::<$word>());
+                        for (word, chunk) in words.iter_mut().zip(chunks) {
+                            *word = <$word>::from_le_bytes(chunk.try_into().unwrap())
+                        }
+                        words
+                    }
+                }
+                impl CompatEndian<$bytes> for [$word; $n] {
+                    #[inline]
+                    fn compat_be(self) -> $bytes {
+                        let mut bytes = <$bytes>::default();
+                        let chunks = AsMut::<[u8]>::as_mut(&mut bytes).chunks_exact_mut(size_of::<$word>());
+                        for (chunk, word) in chunks.zip(self) {
+                            chunk.copy_from_slice(&word.to_be_bytes())
+                        }
+                        bytes
+                    }
+                    #[inline]
+                    fn compat_le(self) -> $bytes {
+                        let mut bytes = <$bytes>::default();
+                        let chunks = AsMut::<[u8]>::as_mut(&mut bytes).chunks_exact_mut(size_of::<$word>());
+                        for (chunk, word) in chunks.zip(self) {
+                            chunk.copy_from_slice(&word.to_le_bytes())
+                        }
+                        bytes
+                    }
+                }
+            )*
+        };
+    }
+
+    compat_words! {
+        alloy_primitives::B256 : [u64; 4];
+        alloy_primitives::B256 : [u32; 8];
+        ethereum_types::H256 : [u64; 4];
+        ethereum_types::H256 : [u32; 8];
+    }
+
+    compat_uint_fixed_bytes! {
+        alloy_primitives::U64, alloy_primitives::B64 : ethereum_types::U64, ethereum_types::H64
+            => alloy_u64_to_eth_h64, eth_h64_to_alloy_u64, eth_u64_to_alloy_b64, alloy_b64_to_eth_u64;
+        alloy_primitives::U128, alloy_primitives::B128 : ethereum_types::U128, ethereum_types::H128
+            => alloy_u128_to_eth_h128, eth_h128_to_alloy_u128, eth_u128_to_alloy_b128, alloy_b128_to_eth_u128;
+        alloy_primitives::U256, alloy_primitives::B256 : ethereum_types::U256, ethereum_types::H256
+            => alloy_u256_to_eth_h256, eth_h256_to_alloy_u256, eth_u256_to_alloy_b256, alloy_b256_to_eth_u256;
+        alloy_primitives::U512, alloy_primitives::B512 : ethereum_types::U512, ethereum_types::H512
+            => alloy_u512_to_eth_h512, eth_h512_to_alloy_u512, eth_u512_to_alloy_b512, alloy_b512_to_eth_u512;
+    }
+
+    // `ethereum_types::Address` is an alias for `H160`, so these also cover `H160`
+    impl Compat<ethereum_types::Address> for alloy_primitives::Address {
+        #[inline]
+        fn compat(self) -> ethereum_types::Address {
+            crate::convert::alloy_address_to_eth_address(self)
+        }
+    }
+    impl Compat<alloy_primitives::Address> for ethereum_types::Address {
+        #[inline]
+        fn compat(self) -> alloy_primitives::Address {
+            crate::convert::eth_address_to_alloy_address(self)
+        }
+    }
+    #[cfg(feature = "test-util")]
+    impl Counterpart for alloy_primitives::Address {
+        type Eth = ethereum_types::Address;
+    }
+    // SAFETY: both are `[u8; 20]` newtypes
+    same_layout!(alloy_primitives::Address, ethereum_types::Address);
+
+    /// Keep the rightmost (low-order) bytes.
+    macro_rules! truncate_fixed_bytes {
+        ($($alloy_big:path, $eth_big:path => $alloy_small:path, $eth_small:path);* $(;)?) => {
+            $(
+                impl CompatTruncate<$eth_small> for $alloy_big {
+                    #[inline]
+                    fn compat_truncate(self) -> $eth_small {
+                        let start = self.len() - core::mem::size_of::<$eth_small>();
+                        <$eth_small>::from_slice(&self[start..])
+                    }
+                }
+                impl CompatTruncate<$alloy_small> for $eth_big {
+                    #[inline]
+                    fn compat_truncate(self) -> $alloy_small {
+                        let start = self.as_bytes().len() - core::mem::size_of::<$alloy_small>();
+                        <$alloy_small>::from_slice(&self[start..])
+                    }
+                }
+            )*
+        };
+    }
+
+    truncate_fixed_bytes! {
+        alloy_primitives::B128, ethereum_types::H128 => alloy_primitives::B64, ethereum_types::H64;
+        alloy_primitives::B256, ethereum_types::H256 => alloy_primitives::B64, ethereum_types::H64;
+        alloy_primitives::B256, ethereum_types::H256 => alloy_primitives::B128, ethereum_types::H128;
+        alloy_primitives::B256, ethereum_types::H256 => alloy_primitives::Address, ethereum_types::Address;
+        alloy_primitives::B512, ethereum_types::H512 => alloy_primitives::B128, ethereum_types::H128;
+        alloy_primitives::B512, ethereum_types::H512 => alloy_primitives::B256, ethereum_types::H256;
+    }
+
+    /// Place the bytes in the rightmost (low-order) positions.
+    macro_rules! extend_fixed_bytes {
+        ($($alloy_small:path, $eth_small:path => $alloy_big:path, $eth_big:path);* $(;)?) => {
+            $(
+                impl CompatExtend<$eth_big> for $alloy_small {
+                    #[inline]
+                    fn compat_extend(self) -> $eth_big {
+                        let mut big = <$eth_big>::zero();
+                        let start = big.as_bytes().len() - self.len();
+                        big[start..].copy_from_slice(self.as_slice());
+                        big
+                    }
+                }
+                impl CompatExtend<$alloy_big> for $eth_small {
+                    #[inline]
+                    fn compat_extend(self) -> $alloy_big {
+                        let mut big = <$alloy_big>::ZERO;
+                        let start = big.len() - self.as_bytes().len();
+                        big[start..].copy_from_slice(self.as_bytes());
+                        big
+                    }
+                }
+            )*
+        };
+    }
+
+    extend_fixed_bytes! {
+        alloy_primitives::B64, ethereum_types::H64 => alloy_primitives::B128, ethereum_types::H128;
+        alloy_primitives::B64, ethereum_types::H64 => alloy_primitives::B256, ethereum_types::H256;
+        alloy_primitives::B128, ethereum_types::H128 => alloy_primitives::B256, ethereum_types::H256;
+        alloy_primitives::Address, ethereum_types::Address => alloy_primitives::B256, ethereum_types::H256;
+        alloy_primitives::B128, ethereum_types::H128 => alloy_primitives::B512, ethereum_types::H512;
+        alloy_primitives::B256, ethereum_types::H256 => alloy_primitives::B512, ethereum_types::H512;
+    }
+
+    /// Like [`CompatTruncate`](crate::CompatTruncate), but checked.
+    impl TryCompat<ethereum_types::Address> for alloy_primitives::B256 {
+        #[inline]
+        fn try_compat(self) -> Result<ethereum_types::Address, CompatError> {
+            match self[..12].iter().all(|it| *it == 0) {
+                true => Ok(self.compat_truncate()),
+                false => Err(CompatError::NonZeroPadding),
+            }
+        }
+    }
+    impl TryCompat<alloy_primitives::Address> for ethereum_types::H256 {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::Address, CompatError> {
+            match self[..12].iter().all(|it| *it == 0) {
+                true => Ok(self.compat_truncate()),
+                false => Err(CompatError::NonZeroPadding),
+            }
+        }
+    }
+
+    /// Addresses as big-endian integers, which matches the left-padded bytes.
+    impl CompatExtend<ethereum_types::U256> for alloy_primitives::Address {
+        #[inline]
+        fn compat_extend(self) -> ethereum_types::U256 {
+            CompatExtend::<ethereum_types::H256>::compat_extend(self).into_uint()
+        }
+    }
+    impl CompatExtend<alloy_primitives::U256> for ethereum_types::Address {
+        #[inline]
+        fn compat_extend(self) -> alloy_primitives::U256 {
+            let FixedBytes(bytes) = CompatExtend::<alloy_primitives::B256>::compat_extend(self);
+            alloy_primitives::U256::from_be_bytes(bytes)
+        }
+    }
+    impl TryCompat<alloy_primitives::Address> for ethereum_types::U256 {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::Address, CompatError> {
+            match self.bits() <= 160 {
+                true => Ok(ethereum_types::H256::from_uint(&self).compat_truncate()),
+                false => Err(CompatError::Overflow),
+            }
+        }
+    }
+    impl TryCompat<ethereum_types::Address> for alloy_primitives::U256 {
+        #[inline]
+        fn try_compat(self) -> Result<ethereum_types::Address, CompatError> {
+            match self.bit_len() <= 160 {
+                true => Ok(alloy_primitives::B256::from(self).compat_truncate()),
+                false => Err(CompatError::Overflow),
+            }
+        }
+    }
+
+    impl<T, U> Compat<Option<U>> for Option<T>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> Option<U> {
+            self.map(T::compat)
+        }
+    }
+
+    impl<T, U, E> Compat<Result<U, E>> for Result<T, E>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> Result<U, E> {
+            self.map(T::compat)
+        }
+    }
+
+    impl<T, U> Compat<core::ops::Range<U>> for core::ops::Range<T>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> core::ops::Range<U> {
+            self.start.compat()..self.end.compat()
+        }
+    }
+
+    impl<T, U, const N: usize> Compat<[U; N]> for [T; N]
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> [U; N] {
+            self.map(T::compat)
+        }
+    }
+
+    macro_rules! compat_tuple {
+        ($($t:ident $u:ident),*) => {
+            impl<$($t, $u),*> Compat<($($u,)*)> for ($($t,)*)
+            where
+                $($t: Compat<$u>,)*
+            {
+                #[allow(non_snake_case)]
+                #[inline]
+                fn compat(self) -> ($($u,)*) {
+                    let ($($t,)*) = self;
+                    ($($t.compat(),)*)
+                }
+            }
+            impl<$($t: Alloy),*> Alloy for ($($t,)*) {}
+            impl<$($t: Eth),*> Eth for ($($t,)*) {}
+        };
+    }
+
+    compat_tuple!(T0 U0, T1 U1);
+    compat_tuple!(T0 U0, T1 U1, T2 U2);
+    compat_tuple!(T0 U0, T1 U1, T2 U2, T3 U3);
+    compat_tuple!(T0 U0, T1 U1, T2 U2, T3 U3, T4 U4);
+    compat_tuple!(T0 U0, T1 U1, T2 U2, T3 U3, T4 U4, T5 U5);
+    compat_tuple!(T0 U0, T1 U1, T2 U2, T3 U3, T4 U4, T5 U5, T6 U6);
+    compat_tuple!(T0 U0, T1 U1, T2 U2, T3 U3, T4 U4, T5 U5, T6 U6, T7 U7);
+
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::vec::Vec<U>> for alloc::vec::Vec<T>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::vec::Vec<U> {
+            self.into_iter().map(T::compat).collect()
+        }
+    }
+
+    /// This clones each element.
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::vec::Vec<U>> for &[T]
+    where
+        T: Clone + Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::vec::Vec<U> {
+            self.iter().cloned().map(T::compat).collect()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::collections::VecDeque<U>> for alloc::collections::VecDeque<T>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::collections::VecDeque<U> {
+            self.into_iter().map(T::compat).collect()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::collections::LinkedList<U>> for alloc::collections::LinkedList<T>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::collections::LinkedList<U> {
+            self.into_iter().map(T::compat).collect()
+        }
+    }
+
+    /// This allocates a new [`Box`](alloc::boxed::Box).
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::boxed::Box<U>> for alloc::boxed::Box<T>
+    where
+        T: Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::boxed::Box<U> {
+            alloc::boxed::Box::new((*self).compat())
+        }
+    }
+
+    /// This allocates a new [`Arc`](alloc::sync::Arc),
+    /// moving the value out of `self` if it is the only strong reference,
+    /// or cloning it otherwise.
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::sync::Arc<U>> for alloc::sync::Arc<T>
+    where
+        T: Clone + Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::sync::Arc<U> {
+            alloc::sync::Arc::new(alloc::sync::Arc::unwrap_or_clone(self).compat())
+        }
+    }
+
+    /// This allocates a new [`Rc`](alloc::rc::Rc),
+    /// moving the value out of `self` if it is the only strong reference,
+    /// or cloning it otherwise.
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::rc::Rc<U>> for alloc::rc::Rc<T>
+    where
+        T: Clone + Compat<U>,
+    {
+        #[inline]
+        fn compat(self) -> alloc::rc::Rc<U> {
+            alloc::rc::Rc::new(alloc::rc::Rc::unwrap_or_clone(self).compat())
+        }
+    }
+
+    /// This always returns [`Cow::Owned`](alloc::borrow::Cow::Owned).
+    #[cfg(feature = "alloc")]
+    impl<'a, 'b, T, U> Compat<alloc::borrow::Cow<'b, U>> for alloc::borrow::Cow<'a, T>
+    where
+        T: Clone + Compat<U>,
+        U: Clone,
+    {
+        #[inline]
+        fn compat(self) -> alloc::borrow::Cow<'b, U> {
+            alloc::borrow::Cow::Owned(self.into_owned().compat())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<K, V, K2, V2, S, S2> Compat<std::collections::HashMap<K2, V2, S2>>
+        for std::collections::HashMap<K, V, S>
+    where
+        K: Compat<K2>,
+        V: Compat<V2>,
+        K2: Eq + core::hash::Hash,
+        S2: core::hash::BuildHasher + Default,
+    {
+        #[inline]
+        fn compat(self) -> std::collections::HashMap<K2, V2, S2> {
+            self.into_iter()
+                .map(|(k, v)| (k.compat(), v.compat()))
+                .collect()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<T, U, S, S2> Compat<std::collections::HashSet<U, S2>> for std::collections::HashSet<T, S>
+    where
+        T: Compat<U>,
+        U: Eq + core::hash::Hash,
+        S2: core::hash::BuildHasher + Default,
+    {
+        #[inline]
+        fn compat(self) -> std::collections::HashSet<U, S2> {
+            self.into_iter().map(T::compat).collect()
+        }
+    }
+
+    /// The entries are re-sorted by the target key's ordering,
+    /// which may differ, e.g. signed integers reinterpreted as unsigned.
+    /// The key conversions in this crate are injective, so the entry count is unchanged.
+    #[cfg(feature = "alloc")]
+    impl<K, V, K2, V2> Compat<alloc::collections::BTreeMap<K2, V2>>
+        for alloc::collections::BTreeMap<K, V>
+    where
+        K: Compat<K2>,
+        V: Compat<V2>,
+        K2: Ord,
+    {
+        #[inline]
+        fn compat(self) -> alloc::collections::BTreeMap<K2, V2> {
+            self.into_iter()
+                .map(|(k, v)| (k.compat(), v.compat()))
+                .collect()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T, U> Compat<alloc::collections::BTreeSet<U>> for alloc::collections::BTreeSet<T>
+    where
+        T: Compat<U>,
+        U: Ord,
+    {
+        #[inline]
+        fn compat(self) -> alloc::collections::BTreeSet<U> {
+            self.into_iter().map(T::compat).collect()
+        }
+    }
+
+    /// Little-endian `u64` limbs to a [`num_bigint::BigUint`].
+    #[cfg(feature = "num-bigint")]
+    #[inline]
+    fn biguint_from_limbs(limbs: &[u64]) -> num_bigint::BigUint {
+        num_bigint::BigUint::new(
+            limbs
+                .iter()
+                .flat_map(|limb| [*limb as u32, (*limb >> 32) as u32])
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "num-bigint")]
+    impl<const BITS: usize, const LIMBS: usize> Compat<num_bigint::BigUint>
+        for alloy_primitives::Uint<BITS, LIMBS>
+    {
+        #[inline]
+        fn compat(self) -> num_bigint::BigUint {
+            biguint_from_limbs(self.as_limbs())
+        }
+    }
+    /// Fails if the value is wider than the target.
+    #[cfg(feature = "num-bigint")]
+    impl<const BITS: usize, const LIMBS: usize> TryCompat<alloy_primitives::Uint<BITS, LIMBS>>
+        for num_bigint::BigUint
+    {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::Uint<BITS, LIMBS>, CompatError> {
+            alloy_primitives::Uint::checked_from_limbs_slice(&self.to_u64_digits())
+                .ok_or(CompatError::Overflow)
+        }
+    }
+
+    macro_rules! compat_biguint {
+        ($($eth:path),* $(,)?) => {
+            $(
+                #[cfg(feature = "num-bigint")]
+                impl Compat<num_bigint::BigUint> for $eth {
+                    #[inline]
+                    fn compat(self) -> num_bigint::BigUint {
+                        biguint_from_limbs(self.limbs())
+                    }
+                }
+                /// Fails if the value is wider than the target.
+                #[cfg(feature = "num-bigint")]
+                impl TryCompat<$eth> for num_bigint::BigUint {
+                    #[inline]
+                    fn try_compat(self) -> Result<$eth, CompatError> {
+                        <$eth>::checked_from_limbs(&self.to_u64_digits()).ok_or(CompatError::Overflow)
+                    }
+                }
+            )*
+        };
+    }
+
+    compat_biguint!(
+        ethereum_types::U64,
+        ethereum_types::U128,
+        ethereum_types::U256,
+        ethereum_types::U512,
+    );
+
+    /// Insertion order is preserved.
+    #[cfg(feature = "indexmap")]
+    impl<K, V, K2, V2, S, S2> Compat<indexmap::IndexMap<K2, V2, S2>> for indexmap::IndexMap<K, V, S>
+    where
+        K: Compat<K2>,
+        V: Compat<V2>,
+        K2: Eq + core::hash::Hash,
+        S2: core::hash::BuildHasher + Default,
+    {
+        #[inline]
+        fn compat(self) -> indexmap::IndexMap<K2, V2, S2> {
+            self.into_iter()
+                .map(|(k, v)| (k.compat(), v.compat()))
+                .collect()
+        }
+    }
+
+    /// The result is inline if it fits, so it stays inline
+    /// (or spilled) if both sides have the same inline capacity.
+    #[cfg(feature = "smallvec")]
+    impl<A, B> Compat<smallvec::SmallVec<B>> for smallvec::SmallVec<A>
+    where
+        A: smallvec::Array,
+        B: smallvec::Array,
+        A::Item: Compat<B::Item>,
+    {
+        #[inline]
+        fn compat(self) -> smallvec::SmallVec<B> {
+            self.into_iter().map(A::Item::compat).collect()
+        }
+    }
+
+    // `ethereum_types` has no bytes type, so use the usual ones
+    #[cfg(feature = "alloc")]
+    impl Compat<alloc::vec::Vec<u8>> for alloy_primitives::Bytes {
+        #[inline]
+        fn compat(self) -> alloc::vec::Vec<u8> {
+            let Self(bytes) = self;
+            bytes.into()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl Compat<alloy_primitives::Bytes> for alloc::vec::Vec<u8> {
+        #[inline]
+        fn compat(self) -> alloy_primitives::Bytes {
+            alloy_primitives::Bytes(self.into())
+        }
+    }
+    impl Compat<alloy_primitives::bytes::Bytes> for alloy_primitives::Bytes {
+        #[inline]
+        fn compat(self) -> alloy_primitives::bytes::Bytes {
+            let Self(bytes) = self;
+            bytes
+        }
+    }
+    impl Compat<alloy_primitives::Bytes> for alloy_primitives::bytes::Bytes {
+        #[inline]
+        fn compat(self) -> alloy_primitives::Bytes {
+            alloy_primitives::Bytes(self)
+        }
+    }
+
+    impl Compat<ethereum_types::Bloom> for alloy_primitives::Bloom {
+        #[inline]
+        fn compat(self) -> ethereum_types::Bloom {
+            crate::convert::alloy_bloom_to_eth_bloom(self)
+        }
+    }
+    impl Compat<alloy_primitives::Bloom> for ethereum_types::Bloom {
+        #[inline]
+        fn compat(self) -> alloy_primitives::Bloom {
+            crate::convert::eth_bloom_to_alloy_bloom(self)
+        }
+    }
+    #[cfg(feature = "test-util")]
+    impl Counterpart for alloy_primitives::Bloom {
+        type Eth = ethereum_types::Bloom;
+    }
+    // SAFETY: both are `[u8; 256]` newtypes
+    same_layout!(alloy_primitives::Bloom, ethereum_types::Bloom);
+
+    // `ethereum_types` has no const-generic hash, so this is only for `alloy_primitives`
+    impl<const N: usize> Compat<[u8; N]> for FixedBytes<N> {
+        #[inline]
+        fn compat(self) -> [u8; N] {
+            crate::convert::alloy_fixed_bytes_to_array(self)
+        }
+    }
+    impl<const N: usize> Compat<FixedBytes<N>> for [u8; N] {
+        #[inline]
+        fn compat(self) -> FixedBytes<N> {
+            crate::convert::array_to_alloy_fixed_bytes(self)
+        }
+    }
+
+    impl Compat<[u8; 256]> for alloy_primitives::Bloom {
+        #[inline]
+        fn compat(self) -> [u8; 256] {
+            crate::convert::alloy_bloom_to_array(self)
+        }
+    }
+    impl Compat<alloy_primitives::Bloom> for [u8; 256] {
+        #[inline]
+        fn compat(self) -> alloy_primitives::Bloom {
+            crate::convert::array_to_alloy_bloom(self)
+        }
+    }
+    impl Compat<[u8; 256]> for ethereum_types::Bloom {
+        #[inline]
+        fn compat(self) -> [u8; 256] {
+            crate::convert::eth_bloom_to_array(self)
+        }
+    }
+    impl Compat<ethereum_types::Bloom> for [u8; 256] {
+        #[inline]
+        fn compat(self) -> ethereum_types::Bloom {
+            crate::convert::array_to_eth_bloom(self)
+        }
+    }
+
+    macro_rules! array_to_eth {
+        ($($n:literal : $eth:path => $to_eth:ident);* $(;)?) => {
+            $(
+                impl Compat<$eth> for [u8; $n] {
+                    #[inline]
+                    fn compat(self) -> $eth {
+                        crate::convert::$to_eth(self)
+                    }
+                }
+            )*
+        };
+    }
+
+    array_to_eth! {
+        4 : ethereum_types::H32 => array_to_eth_h32;
+        8 : ethereum_types::H64 => array_to_eth_h64;
+        16 : ethereum_types::H128 => array_to_eth_h128;
+        20 : ethereum_types::H160 => array_to_eth_h160;
+        32 : ethereum_types::H256 => array_to_eth_h256;
+        33 : ethereum_types::H264 => array_to_eth_h264;
+        64 : ethereum_types::H512 => array_to_eth_h512;
+        65 : ethereum_types::H520 => array_to_eth_h520;
+    }
+
+    impl Compat<alloy_primitives::Address> for [u8; 20] {
+        #[inline]
+        fn compat(self) -> alloy_primitives::Address {
+            crate::convert::array_to_alloy_address(self)
+        }
+    }
+
+    /// Implement [`SameLayout`] from a raw array only,
+    /// so that e.g. `b256.compat_view()` still infers its target.
+    macro_rules! array_layout {
+        ($($n:literal : $($ty:ty),+);* $(;)?) => {
+            $($(
+                unsafe impl SameLayout<$ty> for [u8; $n] {}
+                const _: () = {
+                    assert!(core::mem::size_of::<[u8; $n]>() == core::mem::size_of::<$ty>());
+                    assert!(core::mem::align_of::<[u8; $n]>() == core::mem::align_of::<$ty>());
+                };
+            )+)*
+        };
+    }
+
+    // SAFETY: these are all `[u8; N]` newtypes
+    array_layout! {
+        4 : FixedBytes<4>, ethereum_types::H32;
+        8 : FixedBytes<8>, ethereum_types::H64;
+        16 : FixedBytes<16>, ethereum_types::H128;
+        20 : FixedBytes<20>, alloy_primitives::Address, ethereum_types::H160;
+        32 : FixedBytes<32>, ethereum_types::H256;
+        33 : FixedBytes<33>, ethereum_types::H264;
+        64 : FixedBytes<64>, ethereum_types::H512;
+        65 : FixedBytes<65>, ethereum_types::H520;
+        256 : alloy_primitives::Bloom, ethereum_types::Bloom;
+    }
+
+    /// `(r, s, recovery_id)`, where the recovery id is `0` or `1`.
+    impl Compat<(ethereum_types::H256, ethereum_types::H256, u8)>
+        for alloy_primitives::PrimitiveSignature
+    {
+        #[inline]
+        fn compat(self) -> (ethereum_types::H256, ethereum_types::H256, u8) {
+            (self.r().compat(), self.s().compat(), self.v() as u8)
+        }
+    }
+    /// Accepts any `v` that [`alloy_primitives::normalize_v`] does, e.g. `27` and `28`.
+    impl TryCompat<alloy_primitives::PrimitiveSignature>
+        for (ethereum_types::H256, ethereum_types::H256, u8)
+    {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::PrimitiveSignature, CompatError> {
+            let (r, s, v) = self;
+            let parity =
+                alloy_primitives::normalize_v(v.into()).ok_or(CompatError::InvalidParity)?;
+            Ok(alloy_primitives::PrimitiveSignature::new(
+                r.compat(),
+                s.compat(),
+                parity,
+            ))
+        }
+    }
+
+    /// `r || s || v`, big-endian, where `v` is `27 + recovery_id`.
+    impl Compat<ethereum_types::H520> for alloy_primitives::PrimitiveSignature {
+        #[inline]
+        fn compat(self) -> ethereum_types::H520 {
+            crate::convert::alloy_primitive_signature_to_eth_h520(self)
+        }
+    }
+    /// Accepts any `v` that [`alloy_primitives::normalize_v`] does, e.g. `0` and `27`.
+    impl TryCompat<alloy_primitives::PrimitiveSignature> for ethereum_types::H520 {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::PrimitiveSignature, CompatError> {
+            let r = ethereum_types::H256::from_slice(&self[..32]);
+            let s = ethereum_types::H256::from_slice(&self[32..64]);
+            (r, s, self[64]).try_compat()
+        }
+    }
+
+    /// `(address, topics, data)`.
+    #[cfg(feature = "alloc")]
+    impl
+        Compat<(
+            ethereum_types::Address,
+            alloc::vec::Vec<ethereum_types::H256>,
+            alloc::vec::Vec<u8>,
+        )> for alloy_primitives::Log
+    {
+        #[inline]
+        fn compat(
+            self,
+        ) -> (
+            ethereum_types::Address,
+            alloc::vec::Vec<ethereum_types::H256>,
+            alloc::vec::Vec<u8>,
+        ) {
+            let (topics, data) = self.data.split();
+            (
+                self.address.compat(),
+                crate::compat_vec(topics),
+                data.compat(),
+            )
+        }
+    }
+    /// Fails if there are more than four topics.
+    #[cfg(feature = "alloc")]
+    impl TryCompat<alloy_primitives::Log>
+        for (
+            ethereum_types::Address,
+            alloc::vec::Vec<ethereum_types::H256>,
+            alloc::vec::Vec<u8>,
+        )
+    {
+        #[inline]
+        fn try_compat(self) -> Result<alloy_primitives::Log, CompatError> {
+            let (address, topics, data) = self;
+            alloy_primitives::Log::new(address.compat(), crate::compat_vec(topics), data.compat())
+                .ok_or(CompatError::TooManyTopics)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    use alloy_primitives::{self as alloy, fixed_bytes};
+    use ethereum_types as eth;
+    use std::{
+        borrow::Cow,
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    #[test]
+    fn address() {
+        let alloy = alloy::address!("deadbeefdeadbeefdeadbeefdeadbeef00000000");
+        assert_eq!(format!("{alloy:x}"), format!("{:x}", alloy.compat()));
+        assert_eq!(alloy, alloy.compat().compat::<alloy::Address>());
+    }
+
+    #[test]
+    fn b32() {
+        let alloy = fixed_bytes!("01020304");
+        let eth: eth::H32 = alloy.compat();
+        assert_eq!(eth.as_bytes(), &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(alloy, eth.compat());
+    }
+
+    #[test]
+    fn h160() {
+        let eth = eth::H160::repeat_byte(0xde);
+        let alloy = eth.compat::<alloy::Address>();
+        assert_eq!(eth, alloy.compat::<eth::H160>());
+    }
+
+    #[test]
+    fn primitive_types() {
+        let hash: primitive_types::H256 = alloy::B256::repeat_byte(0xde).compat();
+        assert_eq!(hash, primitive_types::H256::repeat_byte(0xde));
+        let int: primitive_types::U256 = alloy::U256::MAX.compat();
+        assert_eq!(int, primitive_types::U256::MAX);
+        let address: alloy::Address = primitive_types::H160::repeat_byte(0xde).compat();
+        assert_eq!(address, alloy::Address::repeat_byte(0xde));
+    }
+
+    #[test]
+    fn keys() {
+        let secret = eth::Secret::repeat_byte(0xde);
+        let alloy: alloy::B256 = secret.compat();
+        assert_eq!(secret, alloy.compat::<eth::Secret>());
+
+        let public = eth::Public::repeat_byte(0xde);
+        let alloy: alloy::B512 = public.compat();
+        assert_eq!(public, alloy.compat::<eth::Public>());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn b160() {
+        let alloy: alloy::B160 = fixed_bytes!("deadbeefdeadbeefdeadbeefdeadbeef00000000");
+        assert_eq!(
+            format!("{alloy:x}"),
+            format!("{:x}", alloy.compat::<eth::H160>())
+        );
+        assert_eq!(alloy, alloy.compat::<eth::H160>().compat::<alloy::B160>());
+    }
+
+    #[test]
+    fn h264() {
+        let mut alloy = alloy::FixedBytes::<33>::repeat_byte(0xde);
+        alloy[0] = 0x02;
+        let eth: eth::H264 = alloy.compat();
+        assert_eq!(alloy.as_slice(), eth.as_bytes());
+        assert_eq!(alloy, eth.compat());
+    }
+
+    #[test]
+    fn h520() {
+        let mut alloy = alloy::FixedBytes::<65>::repeat_byte(0xde);
+        alloy[64] = 0x1b;
+        let eth: eth::H520 = alloy.compat();
+        assert_eq!(alloy.as_slice(), eth.as_bytes());
+        assert_eq!(alloy, eth.compat());
+    }
+
+    #[test]
+    fn u256() {
+        let alloy = alloy::U256::from_limbs([1, 2, 3, 4]);
+        let eth = eth::U256([1, 2, 3, 4]);
+        assert_eq!(alloy, eth.compat());
+        assert_eq!(eth, alloy.compat());
+        assert_eq!(alloy.to_string(), eth.to_string());
+    }
+
+    #[test]
+    fn i256() {
+        assert_eq!(alloy::I256::MINUS_ONE.compat::<eth::U256>(), eth::U256::MAX);
+        assert_eq!(
+            alloy::I256::MIN.compat::<eth::U256>(),
+            eth::U256::one() << 255
+        );
+        assert_eq!(alloy::I256::MINUS_ONE, eth::U256::MAX.compat());
+        assert_eq!(alloy::I256::MIN, (eth::U256::one() << 255).compat());
+    }
+
+    #[test]
+    fn try_compat_signed() {
+        let max = (eth::U256::one() << 255) - 1;
+        assert_eq!(max.try_compat(), Ok(alloy::I256::MAX));
+        assert_eq!(eth::U256::zero().try_compat(), Ok(alloy::I256::ZERO));
+        assert_eq!(
+            (max + 1).try_compat::<alloy::I256>(),
+            Err(CompatError::Overflow)
+        );
+        assert_eq!(
+            eth::U256::MAX.try_compat::<alloy::I256>(),
+            Err(CompatError::Overflow)
+        );
+    }
+
+    #[test]
+    fn u160() {
+        let max = alloy::aliases::U160::MAX;
+        let eth: eth::U256 = max.compat();
+        assert_eq!(eth, (eth::U256::one() << 160) - 1);
+        assert_eq!(eth.try_compat(), Ok(max));
+        assert_eq!(
+            (eth + 1).try_compat::<alloy::aliases::U160>(),
+            Err(CompatError::Overflow)
+        );
+    }
+
+    /// Pin the signatures that the integer conversions rely on.
+    #[test]
+    fn uint_api() {
+        let _: fn([u64; 4]) -> alloy::U256 = alloy::U256::from_limbs;
+        let _: fn(alloy::U256) -> [u64; 4] = alloy::U256::into_limbs;
+        let _: fn([u64; 4]) -> eth::U256 = eth::U256;
+        let _: fn(&eth::U256) -> eth::H256 = <eth::H256 as eth::BigEndianHash>::from_uint;
+        let _: fn(&eth::H256) -> eth::U256 = <eth::H256 as eth::BigEndianHash>::into_uint;
+
+        assert_eq!(alloy::U256::MAX.compat::<eth::U256>(), eth::U256::MAX);
+        assert_eq!(eth::U256::MAX.compat::<alloy::U256>(), alloy::U256::MAX);
+        assert_eq!(
+            alloy::U256::MAX
+                .compat::<eth::U256>()
+                .compat::<alloy::U256>(),
+            alloy::U256::MAX
+        );
+    }
+
+    #[test]
+    fn big_endian_hash() {
+        use eth::BigEndianHash as _;
+        let alloy = alloy::U256::from_limbs([1, 2, 3, 4]);
+        assert_eq!(
+            alloy.compat::<eth::H256>(),
+            eth::H256::from_uint(&alloy.compat())
+        );
+        let eth = eth::H256::repeat_byte(0xde);
+        assert_eq!(eth.compat::<alloy::U256>(), eth.into_uint().compat());
+    }
+
+    #[test]
+    fn try_compat_slice() {
+        let bytes = [0xde; 33];
+        assert_eq!(
+            bytes[..32].try_compat::<eth::H256>(),
+            Ok(eth::H256::repeat_byte(0xde))
+        );
+        assert_eq!(
+            bytes[..32].try_compat::<alloy::B256>(),
+            Ok(alloy::B256::repeat_byte(0xde))
+        );
+        assert_eq!(
+            bytes[..31].try_compat::<eth::H256>(),
+            Err(CompatError::WrongLength {
+                expected: 32,
+                got: 31
+            })
+        );
+        assert_eq!(
+            bytes[..].try_compat::<alloy::B256>(),
+            Err(CompatError::WrongLength {
+                expected: 32,
+                got: 33
+            })
+        );
+        assert_eq!(
+            bytes[..31]
+                .try_compat::<eth::H256>()
+                .unwrap_err()
+                .to_string(),
+            "expected 32 bytes, got 31"
+        );
+    }
+
+    #[test]
+    fn try_compat_vec() {
+        assert_eq!(
+            vec![0xde; 32].try_compat::<eth::H256>(),
+            Ok(eth::H256::repeat_byte(0xde))
+        );
+        assert_eq!(
+            vec![0xde; 20].try_compat::<eth::Address>(),
+            Ok(eth::Address::repeat_byte(0xde))
+        );
+        assert_eq!(
+            vec![0xde; 31].try_compat::<eth::H256>(),
+            Err(CompatError::WrongLength {
+                expected: 32,
+                got: 31
+            })
+        );
+        assert_eq!(
+            vec![0xde; 66].try_compat::<eth::H520>(),
+            Err(CompatError::WrongLength {
+                expected: 65,
+                got: 66
+            })
+        );
+    }
+
+    #[test]
+    fn domain_aliases() {
+        let eth = eth::H256::repeat_byte(0xde);
+        let tx_hash = eth.compat::<alloy::TxHash>();
+        let block_hash = eth.compat::<alloy::BlockHash>();
+        assert_eq!(tx_hash, alloy::B256::repeat_byte(0xde));
+        assert_eq!(block_hash, tx_hash);
+        assert_eq!(tx_hash.compat::<eth::H256>(), eth);
+    }
+
+    #[test]
+    fn compat_ref() {
+        let alloy = &alloy::B256::repeat_byte(0xde);
+        let eth: eth::H256 = alloy.compat_ref();
+        assert_eq!(alloy.as_slice(), eth.as_bytes());
+
+        let bloom = &alloy::Bloom::repeat_byte(0xde);
+        let eth: eth::Bloom = bloom.compat_ref();
+        assert_eq!(bloom.as_slice(), eth.as_bytes());
+    }
+
+    #[test]
+    fn compat_clone() {
+        struct Header {
+            logs_bloom: alloy::Bloom,
+            hashes: Vec<alloy::B256>,
+        }
+        let header = &Header {
+            logs_bloom: alloy::Bloom::repeat_byte(0xde),
+            hashes: vec![alloy::B256::repeat_byte(0xad)],
+        };
+        let eth: eth::Bloom = header.logs_bloom.compat_clone();
+        assert_eq!(eth, eth::Bloom::repeat_byte(0xde));
+        let eth: Vec<eth::H256> = header.hashes.compat_clone();
+        assert_eq!(eth, [eth::H256::repeat_byte(0xad)]);
+    }
+
+    #[test]
+    fn compat_view() {
+        let alloy = alloy::Bloom::repeat_byte(0xde);
+        let eth: &eth::Bloom = alloy.compat_view();
+        assert_eq!(eth as *const _ as usize, &alloy as *const _ as usize);
+        assert_eq!(
+            serde_json::to_value(alloy).unwrap(),
+            serde_json::to_value(eth).unwrap()
+        );
+        assert_eq!(eth.compat_view::<alloy::Bloom>(), &alloy);
+        assert!(core::ptr::eq(
+            crate::convert::alloy_bloom_as_eth_bloom(&alloy),
+            eth
+        ));
+        assert!(core::ptr::eq(
+            crate::convert::eth_bloom_as_alloy_bloom(eth),
+            &alloy
+        ));
+    }
+
+    #[test]
+    fn compat_mut() {
+        let mut alloy = alloy::B256::ZERO;
+        let eth: &mut eth::H256 = alloy.compat_mut();
+        *eth = eth::H256::repeat_byte(0xde);
+        assert_eq!(alloy, alloy::B256::repeat_byte(0xde));
+
+        let mut eth = eth::U256::zero();
+        let alloy: &mut alloy::U256 = eth.compat_mut();
+        *alloy += alloy::U256::from(1);
+        assert_eq!(eth, eth::U256::one());
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy() {
+        let mut buffer = [0xde; 20]
+            .into_iter()
+            .chain([0xad; 12])
+            .collect::<Vec<u8>>();
+
+        let alloy: &alloy::Address = crate::zerocopy::ref_from(&buffer[..20]).unwrap();
+        assert_eq!(alloy, &alloy::Address::repeat_byte(0xde));
+        assert!(core::ptr::eq(alloy.as_ptr(), buffer.as_ptr()));
+        let eth: &eth::Address = crate::zerocopy::ref_from(&buffer[..20]).unwrap();
+        assert_eq!(eth, &eth::Address::repeat_byte(0xde));
+        assert_eq!(alloy.compat_view::<eth::Address>(), eth);
+        assert_eq!(eth.compat_view::<alloy::Address>(), alloy);
+
+        let (eth, rest) = crate::zerocopy::ref_from_prefix::<20, eth::H160>(&buffer).unwrap();
+        assert_eq!(eth, &eth::H160::repeat_byte(0xde));
+        assert_eq!(rest, [0xad; 12]);
+        assert_eq!(crate::zerocopy::ref_from::<20, eth::H160>(&buffer), None);
+
+        let alloy: &mut alloy::Address = crate::zerocopy::mut_from(&mut buffer[..20]).unwrap();
+        *alloy = alloy::Address::ZERO;
+        assert_eq!(buffer[..20], [0; 20]);
+    }
+
+    #[test]
+    fn compat_hex() {
+        let alloy = alloy::address!("deadbeefdeadbeefdeadbeefdeadbeef00000000");
+        assert_eq!(
+            alloy.compat_hex::<eth::Address>(),
+            format!("0x{:x}", alloy.compat::<eth::Address>())
+        );
+        assert_eq!(
+            alloy.compat_hex::<eth::Address>(),
+            "0xdeadbeefdeadbeefdeadbeefdeadbeef00000000"
+        );
+        assert_eq!(
+            eth::U256::from(0xdead).compat_hex::<alloy::U256>(),
+            "0xdead"
+        );
+    }
+
+    #[test]
+    fn from_alloy_eth() {
+        let alloy = alloy::Address::repeat_byte(0xde);
+        let eth = eth::Address::from_alloy(alloy);
+        assert_eq!(eth, eth::Address::repeat_byte(0xde));
+        assert_eq!(alloy::Address::from_eth(eth), alloy);
+
+        let eth = <(eth::H160, eth::H256)>::from_alloy((alloy, alloy::B256::ZERO));
+        assert_eq!(eth, (alloy.compat(), eth::H256::zero()));
+        let map = std::collections::HashMap::from([(alloy, alloy::U256::from(1))]);
+        let eth = std::collections::HashMap::<eth::Address, eth::U256>::from_alloy(map.clone());
+        assert_eq!(eth[&alloy.compat()], eth::U256::one());
+        assert_eq!(
+            std::collections::HashMap::<alloy::Address, alloy::U256>::from_eth(eth),
+            map
+        );
+
+        let eth = Vec::<eth::U256>::from_alloy(vec![alloy::U256::MAX]);
+        assert_eq!(eth, [eth::U256::MAX]);
+        assert_eq!(Vec::<alloy::U256>::from_eth(eth), [alloy::U256::MAX]);
+    }
+
+    #[test]
+    fn compat_from_into() {
+        fn takes_eth(hash: eth::H256) -> eth::H256 {
+            hash
+        }
+        let alloy = alloy::B256::repeat_byte(0xde);
+        assert_eq!(
+            takes_eth(alloy.compat_into()),
+            eth::H256::compat_from(alloy)
+        );
+    }
+
+    #[test]
+    fn u128() {
+        let alloy = alloy::U128::MAX - alloy::U128::from(1);
+        let eth = eth::U128::MAX - eth::U128::from(1);
+        assert_eq!(alloy, eth.compat());
+        assert_eq!(eth, alloy.compat());
+    }
+
+    #[test]
+    fn primitive_uint() {
+        assert_eq!(alloy::U64::MAX.compat::<u64>(), u64::MAX);
+        assert_eq!(eth::U64::MAX.compat::<u64>(), u64::MAX);
+        assert_eq!(u64::MAX.compat::<alloy::U64>(), alloy::U64::MAX);
+        assert_eq!(u64::MAX.compat::<eth::U64>(), eth::U64::MAX);
+
+        assert_eq!(alloy::U128::MAX.compat::<u128>(), u128::MAX);
+        assert_eq!(eth::U128::MAX.compat::<u128>(), u128::MAX);
+        assert_eq!(u128::MAX.compat::<alloy::U128>(), alloy::U128::MAX);
+        assert_eq!(u128::MAX.compat::<eth::U128>(), eth::U128::MAX);
+    }
+
+    #[test]
+    fn compat_checksum() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let alloy: alloy::Address = checksummed.parse().unwrap();
+        assert_eq!(alloy.compat_checksum(), checksummed);
+        assert_eq!(
+            alloy.compat::<eth::Address>().compat_checksum(),
+            checksummed
+        );
+    }
+
+    #[test]
+    fn pack_addresses() {
+        let alloy = [
+            alloy::Address::repeat_byte(0x01),
+            alloy::Address::repeat_byte(0x02),
+            alloy::Address::repeat_byte(0x03),
+        ];
+        let packed = crate::pack_addresses(&alloy);
+        assert_eq!(packed.len(), 60);
+        assert_eq!(packed[20..40], [0x02; 20]);
+        assert_eq!(crate::unpack_addresses(&packed), Ok(alloy.to_vec()));
+
+        let eth = crate::unpack_addresses::<eth::Address>(&packed).unwrap();
+        assert_eq!(crate::pack_addresses(&eth), packed);
+
+        assert_eq!(crate::unpack_addresses::<eth::Address>(&[]), Ok(vec![]));
+        assert_eq!(
+            crate::unpack_addresses::<alloy::Address>(&packed[..59]),
+            Err(CompatError::WrongLength {
+                expected: 60,
+                got: 59
+            })
+        );
+    }
+
+    #[test]
+    fn block_number() {
+        let number: alloy::BlockNumber = eth::U64::from(u64::MAX - 1).compat();
+        assert_eq!(number, u64::MAX - 1);
+        assert_eq!(number.compat::<eth::U64>(), eth::U64::from(u64::MAX - 1));
+    }
+
+    macro_rules! uint_fixed_bytes {
+        ($($name:ident: $alloy_uint:ty, $alloy_bytes:ty, $eth_uint:ty, $eth_bytes:ty);* $(;)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    let eth: $eth_bytes = <$alloy_uint>::from(1).compat();
+                    assert_eq!(eth, <$eth_bytes>::from_low_u64_be(1));
+                    assert_eq!(<$alloy_uint>::from(1), eth.compat());
+
+                    let eth: $eth_bytes = <$alloy_uint>::MAX.compat();
+                    assert_eq!(eth, <$eth_bytes>::repeat_byte(0xff));
+                    assert_eq!(<$alloy_uint>::MAX, eth.compat());
+
+                    let alloy: $alloy_bytes = <$eth_uint>::from(1).compat();
+                    assert_eq!(alloy, <$alloy_bytes>::with_last_byte(1));
+                    assert_eq!(<$eth_uint>::from(1), alloy.compat());
+
+                    let alloy: $alloy_bytes = <$eth_uint>::MAX.compat();
+                    assert_eq!(alloy, <$alloy_bytes>::repeat_byte(0xff));
+                    assert_eq!(<$eth_uint>::MAX, alloy.compat());
+
+                    let one = <$alloy_uint>::from(1);
+                    let be: $eth_bytes = one.compat_be();
+                    assert_eq!(be[be.as_bytes().len() - 1], 1);
+                    assert_eq!(be, one.compat());
+                    assert_eq!(one, be.compat_be());
+                    let le: $eth_bytes = one.compat_le();
+                    assert_eq!(le[0], 1);
+                    assert_eq!(le.as_bytes(), one.to_le_bytes_vec());
+                    assert_eq!(one, le.compat_le());
+
+                    let one = <$eth_uint>::from(1);
+                    let be: $alloy_bytes = one.compat_be();
+                    assert_eq!(be[be.len() - 1], 1);
+                    assert_eq!(one, be.compat_be());
+                    let le: $alloy_bytes = one.compat_le();
+                    assert_eq!(le[0], 1);
+                    assert_eq!(one, le.compat_le());
+                }
+            )*
+        };
+    }
+
+    uint_fixed_bytes! {
+        u64_h64: alloy::U64, alloy::B64, eth::U64, eth::H64;
+        u128_h128: alloy::U128, alloy::B128, eth::U128, eth::H128;
+        u256_h256: alloy::U256, alloy::B256, eth::U256, eth::H256;
+        u512_h512: alloy::U512, alloy::B512, eth::U512, eth::H512;
+    }
+
+    #[test]
+    fn words() {
+        let hash = alloy::b256!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let be: [u64; 4] = hash.compat_be();
+        assert_eq!(
+            be,
+            [
+                0x0001020304050607,
+                0x08090a0b0c0d0e0f,
+                0x1011121314151617,
+                0x18191a1b1c1d1e1f
+            ]
+        );
+        let le: [u64; 4] = hash.compat_le();
+        assert_eq!(
+            le,
+            [
+                0x0706050403020100,
+                0x0f0e0d0c0b0a0908,
+                0x1716151413121110,
+                0x1f1e1d1c1b1a1918
+            ]
+        );
+        assert_eq!(le, hash.compat_le::<eth::U256>().0);
+        assert_eq!(be.compat_be::<alloy::B256>(), hash);
+        assert_eq!(le.compat_le::<alloy::B256>(), hash);
+
+        let hash: eth::H256 = hash.compat();
+        let be: [u32; 8] = hash.compat_be();
+        assert_eq!(be[0], 0x00010203);
+        assert_eq!(be[7], 0x1c1d1e1f);
+        let le: [u32; 8] = hash.compat_le();
+        assert_eq!(le[0], 0x03020100);
+        assert_eq!(le[7], 0x1f1e1d1c);
+        assert_eq!(be.compat_be::<eth::H256>(), hash);
+        assert_eq!(le.compat_le::<eth::H256>(), hash);
+    }
+
+    #[test]
+    fn uint() {
+        let packed = alloy::aliases::U48::MAX;
+        let eth: eth::U64 = uint_to_eth(packed).unwrap();
+        assert_eq!(eth, eth::U64::from(u64::MAX >> 16));
+        assert_eq!(packed, uint_from_eth(eth).unwrap());
+
+        assert_eq!(uint_to_eth::<eth::U64, 256, 4>(alloy::U256::MAX), None);
+        assert_eq!(uint_from_eth::<48, 1, _>(eth::U64::MAX), None);
+        assert_eq!(
+            uint_from_eth::<256, 4, _>(eth::U64::MAX),
+            Some(alloy::U256::from(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn try_compat() {
+        let alloy = alloy::U256::from(u64::MAX);
+        assert_eq!(alloy.try_compat::<eth::U64>(), Ok(eth::U64::MAX));
+        let eth = eth::U512::from(u128::MAX);
+        assert_eq!(eth.try_compat::<alloy::U128>(), Ok(alloy::U128::MAX));
+
+        let alloy = alloy::U256::from(u64::MAX) + alloy::U256::from(1);
+        assert_eq!(alloy.try_compat::<eth::U64>(), Err(CompatError::Overflow));
+        let eth = eth::U512::from(u128::MAX) + 1;
+        assert_eq!(eth.try_compat::<alloy::U128>(), Err(CompatError::Overflow));
+    }
+
+    #[test]
+    fn vec() {
+        let alloy = vec![
+            alloy::Address::repeat_byte(0xde),
+            alloy::Address::repeat_byte(0xad),
+            alloy::Address::repeat_byte(0xbe),
+        ];
+        let eth: Vec<eth::Address> = alloy.clone().compat();
+        assert_eq!(eth.len(), alloy.len());
+        for (alloy, eth) in alloy.iter().zip(&eth) {
+            assert_eq!(alloy.as_slice(), eth.as_bytes());
+        }
+    }
+
+    #[test]
+    fn compat_vec() {
+        let alloy = vec![
+            alloy::B256::repeat_byte(0xde),
+            alloy::B256::repeat_byte(0xad),
+            alloy::B256::repeat_byte(0xbe),
+        ];
+        let expected = alloy.clone().compat::<Vec<eth::H256>>();
+        let ptr = alloy.as_ptr() as usize;
+        let eth: Vec<eth::H256> = super::compat_vec(alloy);
+        assert_eq!(eth.as_ptr() as usize, ptr);
+        assert_eq!(eth, expected);
+
+        let mut alloy = Vec::with_capacity(8);
+        alloy.push(alloy::B256::repeat_byte(0xde));
+        let ptr = alloy.as_ptr() as usize;
+        let eth: Vec<eth::H256> = super::compat_vec(alloy);
+        assert_eq!(eth.as_ptr() as usize, ptr);
+        assert_eq!(eth.capacity(), 8);
+        assert_eq!(eth, [eth::H256::repeat_byte(0xde)]);
+    }
+
+    #[test]
+    fn result() {
+        let alloy = Ok::<_, &str>(alloy::B256::repeat_byte(0xde));
+        let eth: Result<eth::H256, _> = alloy.compat();
+        assert_eq!(eth, Ok(eth::H256::repeat_byte(0xde)));
+
+        let alloy = Err::<alloy::B256, _>("bad hash");
+        assert_eq!(alloy.compat::<Result<eth::H256, _>>(), Err("bad hash"));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            CompatError::Overflow.to_string(),
+            "value does not fit in the target type"
+        );
+        assert_eq!(
+            CompatError::InvalidParity.to_string(),
+            "invalid signature parity"
+        );
+        assert_eq!(
+            CompatError::WrongLength {
+                expected: 20,
+                got: 19
+            }
+            .to_string(),
+            "expected 20 bytes, got 19"
+        );
+        assert_eq!(
+            CompatError::NonZeroPadding.to_string(),
+            "padding bytes are not zero"
+        );
+        assert_eq!(
+            CompatError::Parse(alloy::hex::FromHexError::OddLength).to_string(),
+            "invalid hex: odd number of digits"
+        );
+        assert_eq!(
+            CompatError::TooManyTopics.to_string(),
+            "a log may have at most 4 topics"
+        );
+
+        let e: Box<dyn std::error::Error> = Box::new(CompatError::Overflow);
+        assert_eq!(e.to_string(), "value does not fit in the target type");
+
+        use std::error::Error as _;
+        assert!(CompatError::Overflow.source().is_none());
+        let e = CompatError::Parse(alloy::hex::FromHexError::OddLength);
+        assert_eq!(
+            e.source().unwrap().to_string(),
+            alloy::hex::FromHexError::OddLength.to_string()
+        );
+    }
+
+    #[test]
+    fn try_compat_from_hex() {
+        let hex = "0x00000000000000000000000000000000000000000000000000000000deadbeef";
+        assert_eq!(
+            crate::try_compat_from_hex::<eth::H256>(hex),
+            Ok(eth::H256::from_low_u64_be(0xdeadbeef))
+        );
+        assert_eq!(
+            crate::try_compat_from_hex::<alloy::B256>(&hex[2..]),
+            Ok(alloy::B256::left_padding_from(&[0xde, 0xad, 0xbe, 0xef]))
+        );
+        assert_eq!(
+            crate::try_compat_from_hex::<eth::Address>("0xdeadbeef"),
+            Err(CompatError::Parse(
+                alloy::hex::FromHexError::InvalidStringLength
+            ))
+        );
+        assert!(matches!(
+            crate::try_compat_from_hex::<eth::H64>("0xdeadbeefdeadbeeg"),
+            Err(CompatError::Parse(
+                alloy::hex::FromHexError::InvalidHexCharacter { c: 'g', .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn range() {
+        let eth = eth::U64::from(100)..eth::U64::from(200);
+        let alloy: core::ops::Range<alloy::U64> = eth.compat();
+        assert_eq!(alloy.start, alloy::U64::from(100));
+        assert_eq!(alloy.end, alloy::U64::from(200));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn compat_par() {
+        let alloy = (0..=255).map(alloy::B256::repeat_byte).collect::<Vec<_>>();
+        let eth: Vec<eth::H256> = crate::compat_par(alloy.clone());
+        assert_eq!(eth, alloy.compat::<Vec<eth::H256>>());
+    }
+
+    #[test]
+    fn compat_slice() {
+        let alloy = [
+            alloy::Address::repeat_byte(0xde),
+            alloy::Address::repeat_byte(0xad),
+        ];
+        let eth: &[eth::Address] = super::compat_slice(&alloy);
+        assert_eq!(eth.len(), alloy.len());
+        assert_eq!(eth.as_ptr() as usize, alloy.as_ptr() as usize);
+        assert_eq!(eth, alloy.compat::<[eth::Address; 2]>());
+    }
+
+    #[test]
+    fn truncate_address() {
+        let hash = alloy::b256!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let address = alloy::address!("0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        assert_eq!(hash.compat_truncate::<eth::Address>(), address.compat());
+        assert_eq!(
+            hash.compat::<eth::H256>()
+                .compat_truncate::<alloy::Address>(),
+            address
+        );
+    }
+
+    #[test]
+    fn truncate_fixed_bytes() {
+        let h512 = eth::H512::from_slice(&core::array::from_fn::<u8, 64, _>(|i| i as u8));
+        let b256: alloy::B256 = h512.compat_truncate();
+        assert_eq!(b256[..], h512[32..]);
+        assert_eq!(
+            h512.compat::<alloy::B512>().compat_truncate::<eth::H256>()[..],
+            h512[32..]
+        );
+
+        let h256 = eth::H256::from_slice(&h512[32..]);
+        let b128: alloy::B128 = h256.compat_truncate();
+        assert_eq!(b128[..], h256[16..]);
+        assert_eq!(
+            h256.compat::<alloy::B256>().compat_truncate::<eth::H128>()[..],
+            h256[16..]
+        );
+    }
+
+    #[test]
+    fn extend_address() {
+        let address = alloy::address!("deadbeefdeadbeefdeadbeefdeadbeef00000000");
+        let word: eth::H256 = address.compat_extend();
+        assert_eq!(word[..12], [0; 12]);
+        assert_eq!(word[12..], address[..]);
+
+        let word: alloy::B256 = address.compat::<eth::Address>().compat_extend();
+        assert_eq!(word[..12], [0; 12]);
+        assert_eq!(word[12..], address[..]);
+    }
+
+    #[test]
+    fn extend_fixed_bytes() {
+        let h128 = eth::H128::repeat_byte(0xde);
+        let b256: alloy::B256 = h128.compat_extend();
+        assert_eq!(b256[..16], [0; 16]);
+        assert_eq!(b256[16..], h128[..]);
+
+        let h256: eth::H256 = h128.compat::<alloy::B128>().compat_extend();
+        assert_eq!(h256[..16], [0; 16]);
+        assert_eq!(h256[16..], h128[..]);
+
+        let h64 = eth::H64::repeat_byte(0xad);
+        let b256: alloy::B256 = h64.compat_extend();
+        assert_eq!(b256[..24], [0; 24]);
+        assert_eq!(b256[24..], h64[..]);
+    }
+
+    #[test]
+    fn try_compat_padded_address() {
+        let padded =
+            alloy::b256!("000000000000000000000000deadbeefdeadbeefdeadbeefdeadbeef00000000");
+        let address = eth::H160::from_slice(&padded[12..]);
+        assert_eq!(padded.try_compat::<eth::H160>(), Ok(address));
+        assert_eq!(
+            padded.compat::<eth::H256>().try_compat(),
+            Ok(address.compat::<alloy::Address>())
+        );
+
+        let hash = alloy::B256::repeat_byte(0xde);
+        assert_eq!(
+            hash.try_compat::<eth::H160>(),
+            Err(CompatError::NonZeroPadding)
+        );
+        assert_eq!(
+            hash.compat::<eth::H256>().try_compat::<alloy::Address>(),
+            Err(CompatError::NonZeroPadding)
+        );
+    }
+
+    #[test]
+    fn address_uint() {
+        let max = alloy::Address::repeat_byte(0xff);
+        let eth: eth::U256 = max.compat_extend();
+        assert_eq!(eth, (eth::U256::one() << 160) - 1);
+        assert_eq!(eth.try_compat(), Ok(max));
+        assert_eq!(
+            (eth + 1).try_compat::<alloy::Address>(),
+            Err(CompatError::Overflow)
+        );
+
+        let max = eth::Address::repeat_byte(0xff);
+        let alloy: alloy::U256 = max.compat_extend();
+        assert_eq!(
+            alloy,
+            alloy::U256::from(1).wrapping_shl(160) - alloy::U256::from(1)
+        );
+        assert_eq!(alloy.try_compat(), Ok(max));
+        assert_eq!(
+            alloy::U256::MAX.try_compat::<eth::Address>(),
+            Err(CompatError::Overflow)
+        );
+
+        let address = alloy::address!("00000000000000000000000000000000deadbeef");
+        assert_eq!(
+            address.compat_extend::<eth::U256>(),
+            eth::U256::from(0xdeadbeef_u64)
+        );
+    }
+
+    #[test]
+    fn option() {
+        let alloy = Some(alloy::B256::repeat_byte(0xde));
+        let eth: Option<eth::H256> = alloy.compat();
+        assert_eq!(eth, Some(eth::H256::repeat_byte(0xde)));
+
+        let alloy = None::<alloy::B256>;
+        assert_eq!(alloy.compat::<Option<eth::H256>>(), None);
+    }
+
+    #[test]
+    fn compat_or_default() {
+        assert_eq!(
+            None::<alloy::U256>.compat_or_default::<eth::U256>(),
+            eth::U256::zero()
+        );
+        assert_eq!(
+            Some(alloy::U256::MAX).compat_or_default::<eth::U256>(),
+            eth::U256::MAX
+        );
+    }
+
+    #[test]
+    fn option_ref() {
+        let hash = alloy::B256::repeat_byte(0xde);
+        let eth: Option<eth::H256> = Some(&hash).compat();
+        assert_eq!(eth, Some(eth::H256::repeat_byte(0xde)));
+        assert_eq!(None::<&alloy::B256>.compat::<Option<eth::H256>>(), None);
+
+        let signature =
+            alloy::PrimitiveSignature::new(alloy::U256::from(1), alloy::U256::from(2), true);
+        let eth: Option<eth::H520> = Some(&signature).compat();
+        assert_eq!(eth, Some(signature.compat()));
+        let small = alloy::aliases::U160::MAX;
+        let eth: Option<eth::U256> = Some(&small).compat();
+        assert_eq!(eth, Some(small.compat()));
+    }
+
+    #[test]
+    fn array() {
+        let alloy = [0xde, 0xad, 0xbe, 0xef].map(alloy::B256::repeat_byte);
+        let eth: [eth::H256; 4] = alloy.compat();
+        assert_eq!(eth, [0xde, 0xad, 0xbe, 0xef].map(eth::H256::repeat_byte));
+    }
+
+    #[test]
+    fn tuple() {
+        let pair = (alloy::Address::repeat_byte(0xde), alloy::U256::from(1));
+        let (a, v): (eth::Address, eth::U256) = pair.compat();
+        assert_eq!((a, v), (eth::Address::repeat_byte(0xde), eth::U256::one()));
+
+        let quad = (
+            eth::Address::repeat_byte(0xde),
+            eth::U64::MAX,
+            eth::Address::repeat_byte(0xad),
+            eth::U128::MAX,
+        );
+        let quad: (alloy::Address, alloy::U64, alloy::Address, alloy::U128) = quad.compat();
+        assert_eq!(
+            quad,
+            (
+                alloy::Address::repeat_byte(0xde),
+                alloy::U64::MAX,
+                alloy::Address::repeat_byte(0xad),
+                alloy::U128::MAX,
+            )
+        );
+    }
+
+    #[test]
+    fn compat_iter() {
+        let alloy = [0xde, 0xad, 0xbe, 0xef].map(alloy::B256::repeat_byte);
+        let lazy = alloy.into_iter().compat_iter().collect::<Vec<eth::H256>>();
+        assert_eq!(lazy, Vec::from(alloy).compat::<Vec<eth::H256>>());
+    }
+
+    #[test]
+    fn compat_collect() {
+        let alloy = [0xde, 0xad, 0xbe, 0xef].map(alloy::B256::repeat_byte);
+        let eth = [0xde, 0xad, 0xbe, 0xef].map(eth::H256::repeat_byte);
+        assert_eq!(alloy.into_iter().compat_collect::<Vec<eth::H256>>(), eth);
+        assert_eq!(
+            alloy.into_iter().compat_collect::<HashSet<eth::H256>>(),
+            HashSet::from(eth)
+        );
+    }
+
+    #[test]
+    fn hash_map() {
+        let alloy = HashMap::from([
+            (alloy::Address::repeat_byte(0xde), alloy::U256::from(1)),
+            (alloy::Address::repeat_byte(0xad), alloy::U256::MAX),
+        ]);
+        let eth: HashMap<eth::Address, eth::U256> = alloy.compat();
+        assert_eq!(
+            eth,
+            HashMap::from([
+                (eth::Address::repeat_byte(0xde), eth::U256::one()),
+                (eth::Address::repeat_byte(0xad), eth::U256::MAX),
+            ])
+        );
+    }
+
+    #[test]
+    fn btree_map() {
+        let eth = BTreeMap::from([
+            (eth::Address::repeat_byte(0xde), eth::U256::one()),
+            (eth::Address::repeat_byte(0xad), eth::U256::MAX),
+            (eth::Address::repeat_byte(0xbe), eth::U256::zero()),
+        ]);
+        let alloy: BTreeMap<alloy::Address, alloy::U256> = eth.clone().compat();
+        assert_eq!(alloy.len(), eth.len());
+        assert!(alloy
+            .into_iter()
+            .eq(eth.into_iter().map(|(k, v)| (k.compat(), v.compat()))));
+
+        let signed = BTreeMap::from([
+            (alloy::I256::MINUS_ONE, alloy::B64::repeat_byte(0xde)),
+            (alloy::I256::ONE, alloy::B64::repeat_byte(0xad)),
+        ]);
+        let unsigned: BTreeMap<eth::U256, eth::H64> = signed.compat();
+        assert!(unsigned.into_iter().eq([
+            (eth::U256::one(), eth::H64::repeat_byte(0xad)),
+            (eth::U256::MAX, eth::H64::repeat_byte(0xde)),
+        ]));
+    }
+
+    #[test]
+    fn sets() {
+        let alloy = [0xde, 0xad, 0xbe, 0xef].map(alloy::Address::repeat_byte);
+        let eth = [0xde, 0xad, 0xbe, 0xef].map(eth::Address::repeat_byte);
+
+        let hash_set: HashSet<eth::Address> = HashSet::from(alloy).compat();
+        assert_eq!(hash_set, HashSet::from(eth));
+
+        let btree_set: BTreeSet<eth::Address> = BTreeSet::from(alloy).compat();
+        assert_eq!(btree_set, BTreeSet::from(eth));
+    }
+
+    #[test]
+    fn borrowed_slice() {
+        let alloy = vec![
+            alloy::Address::repeat_byte(0xde),
+            alloy::Address::repeat_byte(0xad),
+        ];
+        let eth: Vec<eth::Address> = alloy.as_slice().compat();
+        assert_eq!(
+            eth,
+            [
+                eth::Address::repeat_byte(0xde),
+                eth::Address::repeat_byte(0xad)
+            ]
+        );
+    }
+
+    #[test]
+    fn vec_deque() {
+        let mut alloy = VecDeque::new();
+        alloy.push_back(alloy::B256::repeat_byte(0x02));
+        alloy.push_front(alloy::B256::repeat_byte(0x01));
+        alloy.push_back(alloy::B256::repeat_byte(0x03));
+        let eth: VecDeque<eth::H256> = alloy.compat();
+        assert_eq!(eth, [0x01, 0x02, 0x03].map(eth::H256::repeat_byte),);
+    }
 
-impl<T> Compat for T {
-    fn compat<U>(self) -> U
-    where
-        Self: sealed::Compat<U>,
-    {
-        sealed::Compat::compat(self)
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn biguint() {
+        use num_bigint::BigUint;
+
+        let big: BigUint = alloy::U256::MAX.compat();
+        assert_eq!(big, (BigUint::from(1_u8) << 256) - 1_u8);
+        assert_eq!(big.clone().try_compat(), Ok(alloy::U256::MAX));
+        assert_eq!(big.clone().try_compat(), Ok(eth::U256::MAX));
+        assert_eq!(eth::U256::MAX.compat::<BigUint>(), big);
+
+        let big: BigUint = eth::U512::MAX.compat();
+        assert_eq!(big.clone().try_compat(), Ok(alloy::U512::MAX));
+        assert_eq!(big.clone().try_compat(), Ok(eth::U512::MAX));
+
+        let big: BigUint = BigUint::from(1_u8) << 256;
+        assert_eq!(
+            big.clone().try_compat::<alloy::U256>(),
+            Err(CompatError::Overflow)
+        );
+        assert_eq!(big.try_compat::<eth::U256>(), Err(CompatError::Overflow));
+
+        let zero: BigUint = alloy::U256::ZERO.compat();
+        assert_eq!(zero, BigUint::ZERO);
+        assert_eq!(zero.try_compat(), Ok(eth::U256::zero()));
     }
-}
 
-mod sealed {
-    use alloy_primitives::FixedBytes;
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn index_map() {
+        type IndexMap<K, V> = indexmap::IndexMap<K, V, std::hash::RandomState>;
 
-    pub trait Compat<T> {
-        fn compat(self) -> T;
+        let eth = (1..=8)
+            .rev()
+            .map(|it| (eth::Address::repeat_byte(it), eth::U256::from(it)))
+            .collect::<IndexMap<_, _>>();
+        let alloy: IndexMap<alloy::Address, alloy::U256> = eth.clone().compat();
+        assert!(alloy
+            .iter()
+            .map(|(k, v)| (k.compat(), v.compat()))
+            .eq(eth.into_iter()));
+        assert_eq!(
+            alloy.first(),
+            Some((&alloy::Address::repeat_byte(8), &alloy::U256::from(8)))
+        );
     }
 
-    macro_rules! compat_fixed_bytes {
-        ($($(#[$meta:meta])* $alloy:path : $eth:path);* $(;)?) => {
-            $(
-                $(#[$meta])*
-                impl Compat<$eth> for $alloy {
-                    fn compat(self) -> $eth {
-                        let alloy_primitives::FixedBytes(bytes) = self;
-                        $eth(bytes)
-                    }
-                }
-                $(#[$meta])*
-                impl Compat<$alloy> for $eth {
-                    fn compat(self) -> $alloy {
-                        let $eth(bytes) = self;
-                        alloy_primitives::FixedBytes(bytes)
-                    }
-                }
-            )*
-        };
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn small_vec() {
+        use smallvec::SmallVec;
+
+        let alloy: SmallVec<[alloy::B256; 4]> = [0x01, 0x02]
+            .map(alloy::B256::repeat_byte)
+            .into_iter()
+            .collect();
+        let eth: SmallVec<[eth::H256; 4]> = alloy.compat();
+        assert!(!eth.spilled());
+        assert_eq!(eth[..], [0x01, 0x02].map(eth::H256::repeat_byte));
+
+        let alloy: SmallVec<[alloy::B256; 4]> = (1..=6).map(alloy::B256::repeat_byte).collect();
+        assert!(alloy.spilled());
+        let eth: SmallVec<[eth::H256; 4]> = alloy.compat();
+        assert!(eth.spilled());
+        assert!(eth.into_iter().eq((1..=6).map(eth::H256::repeat_byte)));
     }
 
-    compat_fixed_bytes! {
-        // alloy_primitives::B32 : ethereum_types::H32;
-        alloy_primitives::B64 : ethereum_types::H64;
-        alloy_primitives::B128 : ethereum_types::H128;
-        // #[allow(deprecated)] alloy_primitives::B160 : ethereum_types::H160;
-        alloy_primitives::B256 : ethereum_types::H256;
-        // alloy_primitives::B264 : ethereum_types::H264;
-        alloy_primitives::B512 : ethereum_types::H512;
-        // alloy_primitives::B520 : ethereum_types::H520;
+    #[test]
+    fn linked_list() {
+        let alloy = LinkedList::from([0x01, 0x02, 0x03].map(alloy::B256::repeat_byte));
+        let eth: LinkedList<eth::H256> = alloy.compat();
+        assert_eq!(eth.len(), 3);
+        assert!(eth
+            .into_iter()
+            .eq([0x01, 0x02, 0x03].map(eth::H256::repeat_byte)));
     }
 
-    macro_rules! compat_uint {
-        ($($(#[$meta:meta])* $alloy:path : $eth:path);* $(;)?) => {
-            $(
-                $(#[$meta])*
-                impl Compat<$eth> for $alloy {
-                    fn compat(self) -> $eth {
-                        let bytes = self.to_le_bytes::<{<$alloy>::BYTES}>();
-                        <$eth>::from_little_endian(&bytes)
-                    }
+    #[test]
+    fn boxed() {
+        let alloy = Box::new(alloy::B256::repeat_byte(0xde));
+        let eth: Box<eth::H256> = alloy.compat();
+        assert_eq!(*eth, eth::H256::repeat_byte(0xde));
+    }
+
+    #[test]
+    fn arc() {
+        let alloy = Arc::new(alloy::B256::repeat_byte(0xde));
+        let eth: Arc<eth::H256> = alloy.compat();
+        assert_eq!(*eth, eth::H256::repeat_byte(0xde));
+        assert_eq!(Arc::strong_count(&eth), 1);
+
+        let alloy = Arc::new(alloy::B256::repeat_byte(0xad));
+        let shared = Arc::clone(&alloy);
+        let eth: Arc<eth::H256> = alloy.compat();
+        assert_eq!(*eth, eth::H256::repeat_byte(0xad));
+        assert_eq!(*shared, alloy::B256::repeat_byte(0xad));
+        assert_eq!(Arc::strong_count(&shared), 1);
+    }
+
+    #[test]
+    fn rc() {
+        let alloy = Rc::new(alloy::B256::repeat_byte(0xde));
+        let shared = Rc::clone(&alloy);
+        assert_eq!(Rc::strong_count(&shared), 2);
+        let eth: Rc<eth::H256> = alloy.compat();
+        assert_eq!(*eth, eth::H256::repeat_byte(0xde));
+        assert_eq!(Rc::strong_count(&eth), 1);
+        assert_eq!(*shared, alloy::B256::repeat_byte(0xde));
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+
+    #[test]
+    fn cow() {
+        let hash = alloy::B256::repeat_byte(0xde);
+        for alloy in [Cow::Borrowed(&hash), Cow::Owned(hash)] {
+            let eth: Cow<eth::H256> = alloy.compat();
+            assert!(matches!(eth, Cow::Owned(it) if it == eth::H256::repeat_byte(0xde)));
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        for payload in [&b""[..], &b"deadbeef"[..]] {
+            let alloy = alloy::Bytes::copy_from_slice(payload);
+            let vec: Vec<u8> = alloy.clone().compat();
+            assert_eq!(vec, payload);
+            assert_eq!(alloy, vec.compat());
+
+            let bytes: alloy::bytes::Bytes = alloy.clone().compat();
+            assert_eq!(bytes, payload);
+            assert_eq!(alloy, bytes.compat());
+        }
+    }
+
+    #[test]
+    fn compat_saturating() {
+        let alloy = alloy::U256::from(u64::MAX) + alloy::U256::from(1);
+        assert_eq!(alloy.compat_saturating::<eth::U64>(), eth::U64::MAX);
+        let alloy = alloy::U256::from(u64::MAX - 1);
+        assert_eq!(alloy.compat_saturating::<eth::U64>(), eth::U64::MAX - 1);
+
+        let eth = eth::U512::from(u64::MAX) + 1;
+        assert_eq!(eth.compat_saturating::<alloy::U64>(), alloy::U64::MAX);
+        let eth = eth::U512::from(u64::MAX - 1);
+        assert_eq!(
+            eth.compat_saturating::<alloy::U64>(),
+            alloy::U64::from(u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn compat_wrapping() {
+        assert_eq!(
+            alloy::U256::MAX.compat_wrapping::<eth::U64>(),
+            eth::U64::MAX
+        );
+        assert_eq!(
+            eth::U256::MAX.compat_wrapping::<alloy::U64>(),
+            alloy::U64::MAX
+        );
+
+        let alloy = alloy::U512::from_limbs([1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(alloy.compat_wrapping::<eth::U128>(), eth::U128([1, 2]));
+        let eth = eth::U512([1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            eth.compat_wrapping::<alloy::U256>(),
+            alloy::U256::from_limbs([1, 2, 3, 4])
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_pair() {
+        let bytes = (0..=u8::MAX).collect::<Vec<_>>();
+        let mut u = arbitrary::Unstructured::new(&bytes);
+        let (alloy, eth) = super::arbitrary_pair::<alloy::Address, eth::Address>(&mut u).unwrap();
+        assert_eq!(alloy.compat::<eth::Address>(), eth);
+        let (alloy, eth) = super::arbitrary_pair::<alloy::U256, eth::U256>(&mut u).unwrap();
+        assert_eq!(alloy.compat::<eth::U256>(), eth);
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn assert_compat_roundtrip() {
+        crate::assert_compat_roundtrip!(alloy::Address::repeat_byte(0xAB));
+        crate::assert_compat_roundtrip!(alloy::B256::repeat_byte(0xCD));
+        crate::assert_compat_roundtrip!(alloy::B256::repeat_byte(0xCD), eth::H256);
+    }
+
+    #[cfg(any(feature = "proptest", feature = "quickcheck"))]
+    fn roundtrip<T: sealed::Compat<U>, U: sealed::Compat<T>>(t: T) -> T {
+        U::compat(T::compat(t))
+    }
+
+    #[cfg(feature = "quickcheck")]
+    mod quickcheck {
+        use super::super::quickcheck::Arb;
+        use super::*;
+
+        macro_rules! roundtrip {
+            ($($name:ident: $alloy:ty => $eth:ty);* $(;)?) => {
+                ::quickcheck::quickcheck! {
+                    $(
+                        fn $name(alloy: Arb<$alloy>) -> bool {
+                            roundtrip::<_, $eth>(alloy.0) == alloy.0
+                        }
+                    )*
                 }
-                $(#[$meta])*
-                impl Compat<$alloy> for $eth {
-                    fn compat(self) -> $alloy {
-                        let bytes = self.to_little_endian();
-                        <$alloy>::from_le_bytes(bytes)
-                    }
+            };
+        }
+
+        roundtrip! {
+            address: alloy::Address => eth::Address;
+            bloom: alloy::Bloom => eth::Bloom;
+            b64: alloy::B64 => eth::H64;
+            b128: alloy::B128 => eth::H128;
+            b256: alloy::B256 => eth::H256;
+            b512: alloy::B512 => eth::H512;
+            u64: alloy::U64 => eth::U64;
+            u128: alloy::U128 => eth::U128;
+            u256: alloy::U256 => eth::U256;
+            u512: alloy::U512 => eth::U512;
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    mod proptest {
+        use super::super::strategies::*;
+        use super::*;
+
+        macro_rules! roundtrip {
+            ($($name:ident: $strategy:expr => $eth:ty);* $(;)?) => {
+                ::proptest::proptest! {
+                    $(
+                        #[test]
+                        fn $name(alloy in $strategy) {
+                            ::proptest::prop_assert_eq!(alloy, roundtrip::<_, $eth>(alloy));
+                        }
+                    )*
                 }
-            )*
-        };
+            };
+        }
+
+        roundtrip! {
+            address: any_address() => eth::Address;
+            bloom: any_bloom() => eth::Bloom;
+            b64: any_b64() => eth::H64;
+            b128: any_b128() => eth::H128;
+            b256: any_b256() => eth::H256;
+            b512: any_b512() => eth::H512;
+            u64: any_u64() => eth::U64;
+            u128: any_u128() => eth::U128;
+            u256: any_u256() => eth::U256;
+            u512: any_u512() => eth::U512;
+        }
     }
 
-    compat_uint! {
-        alloy_primitives::U64 : ethereum_types::U64;
-        alloy_primitives::U128 : ethereum_types::U128;
-        alloy_primitives::U256 : ethereum_types::U256;
-        alloy_primitives::U512 : ethereum_types::U512;
+    #[cfg(feature = "rlp")]
+    #[test]
+    fn rlp() {
+        let hash = eth::H256::repeat_byte(0xde);
+        assert_eq!(
+            super::rlp::compat_encode::<alloy::B256, _>(hash),
+            ::rlp::encode(&hash)
+        );
+        let int = eth::U256::from(0xdeadbeef_u64);
+        assert_eq!(
+            super::rlp::compat_encode::<alloy::U256, _>(int),
+            ::rlp::encode(&int)
+        );
+        let rlp = ::rlp::encode(&int);
+        assert_eq!(
+            super::rlp::compat_decode::<alloy::U256, eth::U256>(&mut &rlp[..]),
+            Ok(int)
+        );
     }
 
-    impl Compat<ethereum_types::Address> for alloy_primitives::Address {
-        fn compat(self) -> ethereum_types::Address {
-            let Self(FixedBytes(bytes)) = self;
-            ethereum_types::H160(bytes)
-        }
+    #[cfg(feature = "scale")]
+    #[test]
+    fn scale() {
+        use parity_scale_codec::Encode as _;
+
+        let hash = alloy::B256::repeat_byte(0xde);
+        let scale = super::scale::compat_encode::<eth::H256, _>(hash);
+        assert_eq!(scale, eth::H256::repeat_byte(0xde).encode());
+        assert_eq!(scale, hash.as_slice());
+        assert_eq!(
+            super::scale::compat_decode::<eth::H256, alloy::B256>(&mut &scale[..]),
+            Ok(hash)
+        );
+        assert!(super::scale::compat_decode::<eth::H256, alloy::B256>(&mut &scale[1..]).is_err());
     }
-    impl Compat<alloy_primitives::Address> for ethereum_types::Address {
-        fn compat(self) -> alloy_primitives::Address {
-            let Self(bytes) = self;
-            alloy_primitives::Address(FixedBytes(bytes))
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn reserialize() {
+        for alloy in [alloy::U256::ZERO, alloy::U256::from(1), alloy::U256::MAX] {
+            let eth: eth::U256 = super::serde::reserialize(&alloy).unwrap();
+            assert_eq!(eth, alloy.compat());
+            let back: alloy::U256 = super::serde::reserialize(&eth).unwrap();
+            assert_eq!(back, alloy);
         }
     }
 
-    impl Compat<ethereum_types::Bloom> for alloy_primitives::Bloom {
-        fn compat(self) -> ethereum_types::Bloom {
-            let alloy_primitives::Bloom(alloy_primitives::FixedBytes(src)) = self;
-            ethereum_types::Bloom(src)
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_compat_as() {
+        #[serde_with::serde_as]
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Header {
+            #[serde_as(as = "super::serde::CompatAs<alloy::B256>")]
+            hash: eth::H256,
         }
+        let header = Header {
+            hash: eth::H256::repeat_byte(0xde),
+        };
+        let json = serde_json::to_value(&header).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "hash": alloy::B256::repeat_byte(0xde) })
+        );
+        assert_eq!(header, serde_json::from_value(json).unwrap());
     }
-    impl Compat<alloy_primitives::Bloom> for ethereum_types::Bloom {
-        fn compat(self) -> alloy_primitives::Bloom {
-            let ethereum_types::Bloom(src) = self;
-            alloy_primitives::Bloom(alloy_primitives::FixedBytes(src))
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_de_as_eth() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Receipt {
+            #[serde(
+                deserialize_with = "super::serde::de_as_eth",
+                serialize_with = "super::serde::ser_as_alloy"
+            )]
+            hash: eth::H256,
+            #[serde(
+                deserialize_with = "super::serde::de_as_eth",
+                serialize_with = "super::serde::ser_as_alloy"
+            )]
+            gas_used: eth::U256,
         }
+        let json = serde_json::json!({
+            "hash": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+            "gas_used": "0x5208",
+        });
+        let receipt: Receipt = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            receipt.hash,
+            alloy::b256!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421")
+                .compat()
+        );
+        assert_eq!(receipt.gas_used, eth::U256::from(21000));
+        assert_eq!(serde_json::to_value(&receipt).unwrap(), json);
+
+        assert!(serde_json::from_value::<Receipt>(serde_json::json!({
+            "hash": "0xdead",
+            "gas_used": "0x0",
+        }))
+        .is_err());
     }
-}
 
-#[cfg(all(test, feature = "std"))]
-mod tests {
-    use super::*;
+    #[test]
+    fn fixed_bytes_array() {
+        let address: [u8; 20] = core::array::from_fn(|i| i as u8);
+        let alloy: alloy::FixedBytes<20> = address.compat();
+        assert_eq!(alloy.0, address);
+        assert_eq!(alloy.compat::<[u8; 20]>(), address);
 
-    use alloy_primitives::{self as alloy, fixed_bytes};
-    use ethereum_types as eth;
+        let bls: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let alloy: alloy::FixedBytes<48> = bls.compat();
+        assert_eq!(alloy.as_slice(), bls);
+        assert_eq!(alloy.compat::<[u8; 48]>(), bls);
+    }
 
     #[test]
-    fn address() {
-        let alloy = alloy::address!("deadbeefdeadbeefdeadbeefdeadbeef00000000");
-        assert_eq!(format!("{alloy:x}"), format!("{:x}", alloy.compat()));
-        assert_eq!(alloy, alloy.compat().compat::<alloy::Address>());
+    fn bloom_bytes() {
+        let bytes: [u8; 256] = core::array::from_fn(|i| i as u8);
+
+        let alloy: alloy::Bloom = bytes.compat();
+        assert_eq!(alloy.as_slice(), bytes);
+        assert_eq!(alloy.0 .0, bytes);
+        assert_eq!(alloy.compat::<[u8; 256]>(), alloy.0 .0);
+
+        let eth: eth::Bloom = bytes.compat();
+        assert_eq!(eth.as_bytes(), bytes);
+        assert_eq!(eth.0, bytes);
+        assert_eq!(eth.compat::<[u8; 256]>(), eth.0);
     }
 
     #[test]
-    fn u128() {
-        let alloy = alloy::U128::MAX - alloy::U128::from(1);
-        let eth = eth::U128::MAX - eth::U128::from(1);
-        assert_eq!(alloy, eth.compat());
-        assert_eq!(eth, alloy.compat());
+    fn signature() {
+        let r = alloy::b256!("840cfc572845f5786e702984c2a582528cad4b49b2a10b9db1be7fca90058565");
+        let s = alloy::b256!("25e7109ceb98168d95b09b18bbf6b685130e0562f233877d492b94eee0c5b6d1");
+        let signature = alloy::PrimitiveSignature::from_scalars_and_parity(r, s, false);
+
+        let (eth_r, eth_s, v): (eth::H256, eth::H256, u8) = signature.compat();
+        assert_eq!(eth_r, r.compat());
+        assert_eq!(eth_s, s.compat());
+        assert_eq!(v, 0);
+
+        assert_eq!((eth_r, eth_s, 0).try_compat(), Ok(signature));
+        assert_eq!((eth_r, eth_s, 27).try_compat(), Ok(signature));
+        assert_eq!(
+            (eth_r, eth_s, 28).try_compat(),
+            Ok(signature.with_parity(true))
+        );
+        assert_eq!(
+            (eth_r, eth_s, 2).try_compat::<alloy::PrimitiveSignature>(),
+            Err(CompatError::InvalidParity)
+        );
+
+        let bytes: eth::H520 = signature.with_parity(true).compat();
+        assert_eq!(bytes[..32], r[..]);
+        assert_eq!(bytes[32..64], s[..]);
+        assert_eq!(bytes[64], 28);
+        assert_eq!(bytes.try_compat(), Ok(signature.with_parity(true)));
+
+        let mut bytes: eth::H520 = signature.compat();
+        assert_eq!(bytes[64], 27);
+        bytes.0[64] = 0;
+        assert_eq!(bytes.try_compat(), Ok(signature));
+        bytes.0[64] = 2;
+        assert_eq!(
+            bytes.try_compat::<alloy::PrimitiveSignature>(),
+            Err(CompatError::InvalidParity)
+        );
+    }
+
+    #[test]
+    fn log() {
+        let address = alloy::Address::repeat_byte(0xde);
+        let topics = vec![
+            alloy::B256::repeat_byte(0x01),
+            alloy::B256::repeat_byte(0x02),
+        ];
+        let data = alloy::Bytes::from_static(b"deadbeef");
+        let log = alloy::Log::new(address, topics, data).unwrap();
+
+        let (eth_address, eth_topics, eth_data): (eth::Address, Vec<eth::H256>, Vec<u8>) =
+            log.clone().compat();
+        assert_eq!(eth_address, eth::Address::repeat_byte(0xde));
+        assert_eq!(
+            eth_topics,
+            [eth::H256::repeat_byte(0x01), eth::H256::repeat_byte(0x02)]
+        );
+        assert_eq!(eth_data, b"deadbeef");
+        assert_eq!((eth_address, eth_topics, eth_data).try_compat(), Ok(log));
+
+        let too_many = (eth_address, vec![eth::H256::zero(); 5], vec![]);
+        assert_eq!(
+            too_many.try_compat::<alloy::Log>(),
+            Err(CompatError::TooManyTopics)
+        );
     }
 
     #[test]
@@ -166,7 +4134,177 @@ mod tests {
         let alloy = alloy::Bloom(alloy);
         assert_eq!(
             serde_json::to_value(alloy).unwrap(),
-            serde_json::to_value(alloy.compat()).unwrap()
+            serde_json::to_value(alloy.compat::<eth::Bloom>()).unwrap()
+        );
+    }
+}
+
+/// Tests which don't need `std`, so run with `--no-default-features`.
+#[cfg(test)]
+mod core_tests {
+    use super::*;
+    use alloy_primitives as alloy;
+    use ethereum_types as eth;
+
+    #[test]
+    fn const_convert() {
+        const ALLOY: alloy::B256 = alloy::B256::repeat_byte(0xde);
+        const ETH: eth::H256 = convert::alloy_b256_to_eth_h256(ALLOY);
+        const ROUNDTRIP: alloy::B256 = convert::eth_h256_to_alloy_b256(ETH);
+        assert_eq!(ETH, ALLOY.compat());
+        assert_eq!(ROUNDTRIP, ALLOY);
+
+        const ADDRESS: eth::Address = convert::alloy_address_to_eth_address(alloy::Address::ZERO);
+        assert_eq!(
+            convert::eth_address_to_alloy_address(ADDRESS),
+            alloy::Address::ZERO
+        );
+
+        const BALANCE: eth::U256 = convert::alloy_u256_to_eth_u256(alloy::U256::MAX);
+        assert_eq!(BALANCE, eth::U256::MAX);
+        assert_eq!(convert::eth_u256_to_alloy_u256(BALANCE), alloy::U256::MAX);
+    }
+
+    #[test]
+    fn const_convert_uint_fixed_bytes() {
+        let alloy = alloy::U256::from(0xdeadbeef_u64) << 64;
+        let eth = convert::alloy_u256_to_eth_h256(alloy);
+        assert_eq!(eth, alloy.compat::<eth::H256>());
+        assert_eq!(convert::eth_h256_to_alloy_u256(eth), alloy);
+
+        let eth = eth::U512::from(0xdeadbeef_u64) << 448;
+        let alloy = convert::eth_u512_to_alloy_b512(eth);
+        assert_eq!(alloy, eth.compat::<alloy::B512>());
+        assert_eq!(convert::alloy_b512_to_eth_u512(alloy), eth);
+
+        assert_eq!(
+            convert::alloy_u64_to_eth_h64(alloy::U64::from(1)),
+            eth::H64::from_low_u64_be(1)
+        );
+    }
+
+    #[test]
+    fn const_convert_other() {
+        const MINUS_ONE: eth::U256 = convert::alloy_i256_to_eth_u256(alloy::I256::MINUS_ONE);
+        assert_eq!(MINUS_ONE, eth::U256::MAX);
+        assert_eq!(
+            convert::eth_u256_to_alloy_i256(MINUS_ONE),
+            alloy::I256::MINUS_ONE
+        );
+
+        let small = alloy::aliases::U160::MAX;
+        assert_eq!(convert::alloy_u160_to_eth_u256(small), small.compat());
+
+        let signature =
+            alloy::PrimitiveSignature::new(alloy::U256::from(1), alloy::U256::MAX, true);
+        let eth = convert::alloy_primitive_signature_to_eth_h520(signature);
+        assert_eq!(eth.0, signature.as_bytes());
+
+        const ADDRESS: alloy::Address = convert::array_to_alloy_address([0xde; 20]);
+        assert_eq!(ADDRESS, alloy::Address::repeat_byte(0xde));
+        assert_eq!(
+            convert::array_to_eth_h160([0xde; 20]),
+            eth::H160::repeat_byte(0xde)
+        );
+
+        assert_eq!(convert::u128_to_eth_u128(u128::MAX), eth::U128::MAX);
+        assert_eq!(
+            convert::alloy_u128_to_u128(alloy::U128::from(1) << 64),
+            1 << 64
+        );
+        assert_eq!(convert::eth_u64_to_u64(convert::u64_to_eth_u64(7)), 7);
+    }
+
+    #[test]
+    fn compat_truncate_to() {
+        let hash = eth::H256(core::array::from_fn(|ix| ix as u8));
+        assert_eq!(hash.compat_truncate_to::<4>(), [28, 29, 30, 31]);
+        assert_eq!(
+            hash.compat_truncate_to::<20>(),
+            alloy::FixedBytes::<20>::from_slice(&hash[12..])
+        );
+        assert_eq!(
+            hash.compat_truncate_to::<32>(),
+            hash.compat::<alloy::B256>()
+        );
+        assert_eq!(hash.compat_truncate_to::<0>(), alloy::FixedBytes::<0>::ZERO);
+    }
+
+    #[test]
+    fn compat_seal() {
+        let hash = alloy::b256!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+        let sealed = alloy::Sealed::new_with([0xde_u8; 4], |_| hash);
+        assert_eq!(sealed.compat_seal().as_bytes(), hash.as_slice());
+        assert_eq!(*sealed, [0xde; 4]);
+    }
+
+    #[test]
+    fn error_try() {
+        fn narrow(wide: alloy::U256) -> Result<eth::U64, CompatError> {
+            let narrow: eth::U64 = wide.try_compat()?;
+            Ok(narrow + eth::U64::one())
+        }
+        assert_eq!(narrow(alloy::U256::from(1)), Ok(eth::U64::from(2)));
+        assert_eq!(narrow(alloy::U256::MAX), Err(CompatError::Overflow));
+    }
+
+    #[test]
+    fn compat_into_slice() {
+        let alloy = [0x01, 0x02, 0x03].map(alloy::Address::repeat_byte);
+
+        let mut eth = [eth::Address::zero(); 3];
+        assert_eq!(super::compat_into_slice(&alloy, &mut eth), Ok(()));
+        assert_eq!(eth, [0x01, 0x02, 0x03].map(eth::Address::repeat_byte));
+
+        let mut eth = [eth::Address::zero(); 2];
+        assert_eq!(
+            super::compat_into_slice(&alloy, &mut eth),
+            Err(CompatError::WrongLength {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert_eq!(eth, [eth::Address::zero(); 2]);
+
+        let mut eth: [eth::Address; 0] = [];
+        assert_eq!(
+            super::compat_into_slice::<alloy::Address, _>(&[], &mut eth),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn limbs() {
+        let limbs = [1, 2, 3, 4];
+        let eth =
+            convert::eth_u256_from_limbs(convert::alloy_u256_limbs(alloy::U256::from_limbs(limbs)));
+        assert_eq!(eth, eth::U256([1, 2, 3, 4]));
+        assert_eq!(eth.low_u64(), 1);
+        let alloy = convert::alloy_u256_from_limbs(convert::eth_u256_limbs(eth));
+        assert_eq!(
+            alloy,
+            alloy::U256::from(1)
+                | (alloy::U256::from(4) << 192)
+                | (alloy::U256::from(3) << 128)
+                | (alloy::U256::from(2) << 64)
+        );
+
+        assert_eq!(convert::eth_u256_limbs(eth::U256::one()), [1, 0, 0, 0]);
+        assert_eq!(
+            convert::alloy_u256_limbs(alloy::U256::from(1)),
+            [1, 0, 0, 0]
         );
     }
+
+    #[test]
+    fn bloom() {
+        let mut bytes = [0; 256];
+        for chunk in bytes.chunks_exact_mut(8) {
+            chunk.copy_from_slice(b"deadbeef");
+        }
+        let alloy = alloy::Bloom::new(bytes);
+        let eth: eth::Bloom = alloy.compat();
+        assert_eq!(eth.as_bytes(), bytes);
+        assert_eq!(eth.compat::<alloy::Bloom>(), alloy);
+    }
 }
@@ -0,0 +1,48 @@
+//! [`quickcheck`] support for [`alloy_primitives`] types.
+//!
+//! [`Arb`] implements [`Arbitrary`] for the types in this crate,
+//! which may then be [`Compat`](crate::Compat)-ed to their [`ethereum_types`] counterparts.
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! use alloy_compat::{quickcheck::Arb, Compat as _};
+//!
+//! fn prop(Arb(alloy): Arb<alloy::B256>) -> bool {
+//!     alloy.compat::<eth::H256>().compat::<alloy::B256>() == alloy
+//! }
+//! ::quickcheck::quickcheck(prop as fn(_) -> _);
+//! ```
+
+use ::quickcheck::{Arbitrary, Gen};
+use alloy_primitives::{Address, Bloom, FixedBytes, Uint};
+
+/// A wrapper implementing [`Arbitrary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Arb<T>(pub T);
+
+impl<const N: usize> Arbitrary for Arb<FixedBytes<N>> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self(FixedBytes(core::array::from_fn(|_| u8::arbitrary(g))))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Arbitrary for Arb<Uint<BITS, LIMBS>> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let limbs: [u64; LIMBS] = core::array::from_fn(|_| u64::arbitrary(g));
+        Self(Uint::wrapping_from_limbs_slice(&limbs))
+    }
+}
+
+impl Arbitrary for Arb<Address> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let Arb(bytes) = Arbitrary::arbitrary(g);
+        Self(Address(bytes))
+    }
+}
+
+impl Arbitrary for Arb<Bloom> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let Arb(bytes) = Arbitrary::arbitrary(g);
+        Self(Bloom(bytes))
+    }
+}
@@ -0,0 +1,38 @@
+//! RLP-encode [`ethereum_types`] values with [`alloy_rlp`], so the output always
+//! matches what [`alloy_primitives`] would produce.
+//!
+//! For the types in this crate, this is the same as the `rlp` crate's encoding.
+
+use crate::sealed::Compat;
+use alloc::vec::Vec;
+
+/// RLP-encode `value` as `T`.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let hash = eth::H256::repeat_byte(0xde);
+/// let rlp = alloy_compat::rlp::compat_encode::<alloy::B256, _>(hash);
+/// assert_eq!(rlp, alloy_rlp::encode(alloy::B256::repeat_byte(0xde)));
+/// ```
+pub fn compat_encode<T, U>(value: U) -> Vec<u8>
+where
+    U: Compat<T>,
+    T: alloy_rlp::Encodable,
+{
+    alloy_rlp::encode(value.compat())
+}
+
+/// RLP-decode a `T`, and convert it.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// let rlp = alloy_rlp::encode(alloy::U256::from(1));
+/// let int: eth::U256 = alloy_compat::rlp::compat_decode::<alloy::U256, _>(&mut &rlp[..]).unwrap();
+/// assert_eq!(int, eth::U256::one());
+/// ```
+pub fn compat_decode<T, U>(buf: &mut &[u8]) -> alloy_rlp::Result<U>
+where
+    T: alloy_rlp::Decodable + Compat<U>,
+{
+    T::decode(buf).map(Compat::compat)
+}
@@ -0,0 +1,41 @@
+//! SCALE-encode [`alloy_primitives`] values with [`parity_scale_codec`],
+//! using the [`ethereum_types`] encoding.
+
+use crate::sealed::Compat;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+
+/// SCALE-encode `value` as `T`.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use parity_scale_codec::Encode as _;
+///
+/// let hash = alloy::B256::repeat_byte(0xde);
+/// let scale = alloy_compat::scale::compat_encode::<eth::H256, _>(hash);
+/// assert_eq!(scale, eth::H256::repeat_byte(0xde).encode());
+/// ```
+pub fn compat_encode<T, U>(value: U) -> Vec<u8>
+where
+    U: Compat<T>,
+    T: Encode,
+{
+    value.compat().encode()
+}
+
+/// SCALE-decode a `T`, and convert it.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use parity_scale_codec::Encode as _;
+///
+/// let scale = eth::U256::one().encode();
+/// let int: alloy::U256 = alloy_compat::scale::compat_decode::<eth::U256, _>(&mut &scale[..]).unwrap();
+/// assert_eq!(int, alloy::U256::from(1));
+/// ```
+pub fn compat_decode<T, U>(input: &mut &[u8]) -> Result<U, parity_scale_codec::Error>
+where
+    T: Decode + Compat<U>,
+{
+    T::decode(input).map(Compat::compat)
+}
@@ -0,0 +1,100 @@
+//! (De)serialize [`ethereum_types`] values using the [`alloy_primitives`] representation,
+//! and vice-versa.
+
+use crate::sealed::{AlloyCounterpart, Compat};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::marker::PhantomData;
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// A [`serde_with`] adapter which (de)serializes a value as `T`,
+/// converting with [`Compat`](crate::Compat).
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::serde::CompatAs;
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Header {
+///     #[serde_as(as = "CompatAs<alloy::B256>")]
+///     hash: eth::H256,
+/// }
+/// ```
+pub struct CompatAs<T>(PhantomData<T>);
+
+impl<T, U> SerializeAs<U> for CompatAs<T>
+where
+    U: Clone + Compat<T>,
+    T: Serialize,
+{
+    fn serialize_as<S: Serializer>(source: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        source.clone().compat().serialize(serializer)
+    }
+}
+
+impl<'de, T, U> DeserializeAs<'de, U> for CompatAs<T>
+where
+    T: Deserialize<'de> + Compat<U>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<U, D::Error> {
+        T::deserialize(deserializer).map(Compat::compat)
+    }
+}
+
+/// Convert `value` by serializing it, and deserializing the result as `U`.
+///
+/// This checks that the two types are JSON-compatible,
+/// which says nothing about non-human-readable formats.
+/// [`Compat`](crate::Compat) is the fast alternative.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::Compat as _;
+///
+/// let alloy = alloy::U256::from(0xdeadbeef_u64);
+/// let eth: eth::U256 = alloy_compat::serde::reserialize(&alloy).unwrap();
+/// assert_eq!(eth, alloy.compat());
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn reserialize<T, U>(value: &T) -> Result<U, serde_json::Error>
+where
+    T: Serialize + Compat<U>,
+    U: ::serde::de::DeserializeOwned,
+{
+    serde_json::from_value(serde_json::to_value(value)?)
+}
+
+/// Deserialize an [`ethereum_types`] value using the [`alloy_primitives`] representation.
+///
+/// ```
+/// # use ethereum_types as eth;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Header {
+///     #[serde(
+///         deserialize_with = "alloy_compat::serde::de_as_eth",
+///         serialize_with = "alloy_compat::serde::ser_as_alloy"
+///     )]
+///     hash: eth::H256,
+/// }
+/// ```
+pub fn de_as_eth<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: AlloyCounterpart,
+    T::Alloy: Deserialize<'de>,
+{
+    CompatAs::<T::Alloy>::deserialize_as(deserializer)
+}
+
+/// Serialize an [`ethereum_types`] value using the [`alloy_primitives`] representation.
+///
+/// See [`de_as_eth`].
+pub fn ser_as_alloy<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AlloyCounterpart + Clone,
+    T::Alloy: Serialize,
+{
+    CompatAs::<T::Alloy>::serialize_as(value, serializer)
+}
@@ -0,0 +1,73 @@
+//! [`proptest`] strategies for [`alloy_primitives`] types.
+//!
+//! Use [`compat`] to get strategies for the [`ethereum_types`] counterparts.
+
+use crate::sealed::Compat;
+use alloy_primitives::{
+    Address, Bloom, FixedBytes, Uint, B128, B256, B512, B64, U128, U256, U512, U64,
+};
+use proptest::{collection::vec, num, strategy::Strategy};
+
+/// Convert the values of `strategy`.
+///
+/// ```
+/// # use alloy_primitives as alloy; use ethereum_types as eth;
+/// use alloy_compat::strategies;
+/// use proptest::strategy::Strategy;
+///
+/// fn any_h256() -> impl Strategy<Value = eth::H256> {
+///     strategies::compat(strategies::any_b256())
+/// }
+/// ```
+pub fn compat<S, T>(strategy: S) -> impl Strategy<Value = T>
+where
+    S: Strategy,
+    S::Value: Compat<T>,
+    T: core::fmt::Debug,
+{
+    strategy.prop_map(Compat::compat)
+}
+
+/// Any [`FixedBytes`].
+pub fn any_fixed_bytes<const N: usize>() -> impl Strategy<Value = FixedBytes<N>> {
+    vec(num::u8::ANY, N).prop_map(|bytes| FixedBytes::from_slice(&bytes))
+}
+
+/// Any [`Uint`].
+pub fn any_uint<const BITS: usize, const LIMBS: usize>() -> impl Strategy<Value = Uint<BITS, LIMBS>>
+{
+    vec(num::u64::ANY, LIMBS).prop_map(|limbs| Uint::wrapping_from_limbs_slice(&limbs))
+}
+
+/// Any [`Address`].
+pub fn any_address() -> impl Strategy<Value = Address> {
+    any_fixed_bytes().prop_map(Address)
+}
+
+/// Any [`Bloom`].
+pub fn any_bloom() -> impl Strategy<Value = Bloom> {
+    any_fixed_bytes().prop_map(Bloom)
+}
+
+macro_rules! any {
+    ($($(#[$meta:meta])* $name:ident -> $ty:ty = $strategy:ident);* $(;)?) => {
+        $(
+            #[doc = concat!("Any [`", stringify!($ty), "`].")]
+            $(#[$meta])*
+            pub fn $name() -> impl Strategy<Value = $ty> {
+                $strategy()
+            }
+        )*
+    };
+}
+
+any! {
+    any_b64 -> B64 = any_fixed_bytes;
+    any_b128 -> B128 = any_fixed_bytes;
+    any_b256 -> B256 = any_fixed_bytes;
+    any_b512 -> B512 = any_fixed_bytes;
+    any_u64 -> U64 = any_uint;
+    any_u128 -> U128 = any_uint;
+    any_u256 -> U256 = any_uint;
+    any_u512 -> U512 = any_uint;
+}
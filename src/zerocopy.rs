@@ -0,0 +1,49 @@
+//! View byte buffers as fixed bytes of either crate, without copying, using [`zerocopy`].
+//!
+//! Every fixed-bytes type here has the layout of a `[u8; N]`,
+//! so a buffer is viewed as an array, which is then reinterpreted
+//! with [`Compat::compat_view`](crate::Compat::compat_view).
+//!
+//! ```
+//! # use alloy_primitives as alloy; use ethereum_types as eth;
+//! use alloy_compat::Compat as _;
+//!
+//! let buffer = [0xde; 20];
+//! let alloy: &alloy::Address = alloy_compat::zerocopy::ref_from(&buffer).unwrap();
+//! let eth: &eth::Address = alloy.compat_view();
+//! assert_eq!(eth, &eth::Address::repeat_byte(0xde));
+//! ```
+
+use crate::sealed::SameLayout;
+use crate::Compat as _;
+use zerocopy::Ref;
+
+/// View `bytes` as `T`, or [`None`] if the length is wrong.
+#[inline]
+pub fn ref_from<const N: usize, T>(bytes: &[u8]) -> Option<&T>
+where
+    [u8; N]: SameLayout<T>,
+{
+    Ref::<_, [u8; N]>::new(bytes).map(|array| array.into_ref().compat_view())
+}
+
+/// View `bytes` as `T`, or [`None`] if the length is wrong,
+/// so writes through the returned reference are visible in `bytes`.
+#[inline]
+pub fn mut_from<const N: usize, T>(bytes: &mut [u8]) -> Option<&mut T>
+where
+    [u8; N]: SameLayout<T>,
+{
+    Ref::<_, [u8; N]>::new(bytes).map(|array| array.into_mut().compat_mut())
+}
+
+/// View the first bytes of `bytes` as `T`, returning it and the rest,
+/// or [`None`] if `bytes` is too short.
+#[inline]
+pub fn ref_from_prefix<const N: usize, T>(bytes: &[u8]) -> Option<(&T, &[u8])>
+where
+    [u8; N]: SameLayout<T>,
+{
+    Ref::<_, [u8; N]>::new_from_prefix(bytes)
+        .map(|(array, rest)| (array.into_ref().compat_view(), rest))
+}
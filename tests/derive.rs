@@ -0,0 +1,8 @@
+#![cfg(feature = "derive")]
+
+#[test]
+fn derive() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass-*.rs");
+    t.compile_fail("tests/ui/fail-*.rs");
+}
@@ -0,0 +1,12 @@
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+
+alloy_compat::assert_layout_compatible!(alloy::B256, eth::H256);
+alloy_compat::assert_layout_compatible!(alloy::Address, eth::Address);
+alloy_compat::assert_layout_compatible!(alloy::Bloom, eth::Bloom);
+alloy_compat::assert_layout_compatible!(alloy::U256, eth::U256);
+
+#[test]
+fn in_fn() {
+    alloy_compat::assert_layout_compatible!(alloy::U512, eth::U512,);
+}
@@ -0,0 +1,31 @@
+use alloy_compat::Compat as _;
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq)]
+struct Hash<T>(T);
+alloy_compat::impl_compat_newtype!(Hash);
+
+#[derive(Debug, PartialEq)]
+struct Wei;
+
+#[derive(Debug, PartialEq)]
+struct Tagged<T, Unit>(T, PhantomData<Unit>);
+alloy_compat::impl_compat_newtype!(Tagged<Unit>);
+
+#[test]
+fn newtype() {
+    let eth: Hash<eth::H256> = Hash(alloy::B256::repeat_byte(0xde)).compat();
+    assert_eq!(eth, Hash(eth::H256::repeat_byte(0xde)));
+
+    let alloy: Option<Hash<alloy::B256>> = Some(eth).compat();
+    assert_eq!(alloy, Some(Hash(alloy::B256::repeat_byte(0xde))));
+}
+
+#[test]
+fn tagged() {
+    let alloy = Tagged(alloy::U256::MAX, PhantomData::<Wei>);
+    let eth: Tagged<eth::U256, Wei> = alloy.compat();
+    assert_eq!(eth, Tagged(eth::U256::MAX, PhantomData));
+}
@@ -0,0 +1,9 @@
+use alloy_compat::Compat;
+
+#[derive(Compat)]
+#[compat(into = "Other")]
+enum Mine {}
+
+enum Other {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use alloy_compat::Compat;
+
+#[derive(Compat)]
+struct Mine {}
+
+fn main() {}
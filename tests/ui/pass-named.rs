@@ -0,0 +1,36 @@
+use alloy_compat::Compat;
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+
+#[derive(Compat)]
+#[compat(into = "EthHeader")]
+struct AlloyHeader {
+    miner: alloy::Address,
+    hash: alloy::B256,
+    difficulty: alloy::U256,
+}
+
+#[derive(Compat)]
+#[compat(into = "AlloyHeader")]
+struct EthHeader {
+    miner: eth::Address,
+    hash: eth::H256,
+    difficulty: eth::U256,
+}
+
+fn main() {
+    let alloy = AlloyHeader {
+        miner: alloy::Address::repeat_byte(0xde),
+        hash: alloy::B256::repeat_byte(0xad),
+        difficulty: alloy::U256::from(1),
+    };
+    let eth: EthHeader = alloy.compat();
+    assert_eq!(eth.miner, eth::Address::repeat_byte(0xde));
+    assert_eq!(eth.hash, eth::H256::repeat_byte(0xad));
+    assert_eq!(eth.difficulty, eth::U256::one());
+
+    let alloy: AlloyHeader = eth.compat();
+    assert_eq!(alloy.miner, alloy::Address::repeat_byte(0xde));
+    assert_eq!(alloy.hash, alloy::B256::repeat_byte(0xad));
+    assert_eq!(alloy.difficulty, alloy::U256::from(1));
+}
@@ -0,0 +1,21 @@
+use alloy_compat::Compat;
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+
+#[derive(Compat)]
+#[compat(into = "EthHeader")]
+struct AlloyHeader(alloy::Address, alloy::B256, alloy::U256);
+
+struct EthHeader(eth::Address, eth::H256, eth::U256);
+
+fn main() {
+    let alloy = AlloyHeader(
+        alloy::Address::repeat_byte(0xde),
+        alloy::B256::repeat_byte(0xad),
+        alloy::U256::from(1),
+    );
+    let EthHeader(miner, hash, difficulty) = alloy.compat();
+    assert_eq!(miner, eth::Address::repeat_byte(0xde));
+    assert_eq!(hash, eth::H256::repeat_byte(0xad));
+    assert_eq!(difficulty, eth::U256::one());
+}
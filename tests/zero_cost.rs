@@ -0,0 +1,43 @@
+//! Conversions between types with the same layout should compile to a plain move.
+//!
+//! Inspect the optimized output with [`cargo-show-asm`](https://crates.io/crates/cargo-show-asm):
+//! ```text
+//! cargo asm --release --test zero_cost address_alloy_to_eth
+//! ```
+//! Each wrapper should be a single copy of the argument into the return slot,
+//! with no calls, e.g. on x86_64:
+//! ```text
+//! address_alloy_to_eth:
+//!         mov     rax, rdi
+//!         mov     ecx, dword ptr [rsi + 16]
+//!         mov     dword ptr [rdi + 16], ecx
+//!         movups  xmm0, xmmword ptr [rsi]
+//!         movups  xmmword ptr [rdi], xmm0
+//!         ret
+//! ```
+
+use alloy_compat::Compat as _;
+use alloy_primitives as alloy;
+use ethereum_types as eth;
+
+#[no_mangle]
+pub fn address_alloy_to_eth(address: alloy::Address) -> eth::Address {
+    address.compat()
+}
+
+#[no_mangle]
+pub fn address_eth_to_alloy(address: eth::Address) -> alloy::Address {
+    address.compat()
+}
+
+#[no_mangle]
+pub fn u256_alloy_to_eth(int: alloy::U256) -> eth::U256 {
+    int.compat()
+}
+
+#[test]
+fn zero_cost() {
+    let address = alloy::Address::repeat_byte(0xde);
+    assert_eq!(address_eth_to_alloy(address_alloy_to_eth(address)), address);
+    assert_eq!(u256_alloy_to_eth(alloy::U256::MAX), eth::U256::MAX);
+}